@@ -0,0 +1,224 @@
+//! Moves and buttons dispatched through the Chrome DevTools Protocol, skipping
+//! the per-move overhead of WebDriver actions.
+//!
+//! Only Chromium based browsers answer CDP commands, whether a session does is
+//! checked once and remembered, the others keep moving through WebDriver.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use serde_json::json;
+use thirtyfour::error::WebDriverResult;
+use thirtyfour::extensions::cdp::ChromeDevTools;
+use thirtyfour::{Key, WebDriver};
+
+use crate::time::{sleep, Duration};
+use crate::MouseButtonAction;
+
+/// Whether the session answered CDP commands, keyed by session id
+fn support() -> &'static Mutex<HashMap<String, bool>> {
+    static SUPPORT: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
+    SUPPORT.get_or_init(Default::default)
+}
+
+/// Identifier of the script run on every new document, keyed by session id
+fn new_document_scripts() -> &'static Mutex<HashMap<String, String>> {
+    static SCRIPTS: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    SCRIPTS.get_or_init(Default::default)
+}
+
+/// Drop whether the session supports CDP and its new document script
+pub(crate) fn forget(driver: &WebDriver) {
+    let session_id = driver.session_id().to_string();
+    support().lock().unwrap().remove(&session_id);
+    new_document_scripts().lock().unwrap().remove(&session_id);
+}
+
+/// Whether the session answers CDP commands, asking it only the first time
+async fn is_supported(driver: &WebDriver, devtools: &ChromeDevTools) -> bool {
+    let session_id = driver.session_id().to_string();
+    let known = support().lock().unwrap().get(&session_id).copied();
+    match known {
+        Some(supported) => supported,
+        None => {
+            let supported = devtools
+                .execute_cdp_with_params("Browser.getVersion", json!({}))
+                .await
+                .is_ok();
+            support().lock().unwrap().insert(session_id, supported);
+            supported
+        }
+    }
+}
+
+/// Runs `script` on every new document before its own scripts, replacing the
+/// script registered before, `None` only removing it
+///
+/// Does nothing on browsers without CDP
+pub(crate) async fn evaluate_on_new_document(
+    driver: &WebDriver,
+    script: Option<&str>,
+) -> WebDriverResult<()> {
+    let devtools = ChromeDevTools::new(driver.handle.clone());
+    if !is_supported(driver, &devtools).await {
+        return Ok(());
+    }
+
+    let session_id = driver.session_id().to_string();
+    let registered = new_document_scripts().lock().unwrap().remove(&session_id);
+    if let Some(identifier) = registered {
+        devtools
+            .execute_cdp_with_params(
+                "Page.removeScriptToEvaluateOnNewDocument",
+                json!({ "identifier": identifier }),
+            )
+            .await?;
+    }
+    if let Some(script) = script {
+        let added = devtools
+            .execute_cdp_with_params(
+                "Page.addScriptToEvaluateOnNewDocument",
+                json!({ "source": script }),
+            )
+            .await?;
+        if let Some(identifier) = added["identifier"].as_str() {
+            new_document_scripts()
+                .lock()
+                .unwrap()
+                .insert(session_id, identifier.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Mouse moved and pressed through CDP, whose pointer is apart from the one of
+/// WebDriver actions, keeping track of the held button so drags stay pressed
+pub(crate) struct CdpMouse {
+    devtools: ChromeDevTools,
+    held: Option<&'static str>,
+    modifiers: u8,
+}
+
+impl CdpMouse {
+    /// `None` when the browser doesn't support CDP
+    pub(crate) async fn connect(driver: &WebDriver, modifiers: &[Key]) -> Option<Self> {
+        let devtools = ChromeDevTools::new(driver.handle.clone());
+        is_supported(driver, &devtools).await.then(|| CdpMouse {
+            devtools,
+            held: None,
+            modifiers: modifier_flags(modifiers),
+        })
+    }
+
+    /// Performs the button action at the viewport position the mouse was last
+    /// moved to, holding the button of clicks down for `hold_ms` when given
+    pub(crate) async fn button(
+        &mut self,
+        action: &MouseButtonAction,
+        at: (i64, i64),
+        hold_ms: Option<u64>,
+    ) -> WebDriverResult<()> {
+        match action {
+            MouseButtonAction::None => {}
+            MouseButtonAction::LeftClick => self.click("left", 1, at, hold_ms).await?,
+            MouseButtonAction::RightClick => self.click("right", 1, at, hold_ms).await?,
+            MouseButtonAction::MiddleClick => self.click("middle", 1, at, hold_ms).await?,
+            // The second click is told apart by its click count
+            MouseButtonAction::DoubleClick => {
+                self.click("left", 1, at, hold_ms).await?;
+                self.click("left", 2, at, hold_ms).await?;
+            }
+            MouseButtonAction::LeftHold => self.press("left", 1, at).await?,
+            MouseButtonAction::RightHold => self.press("right", 1, at).await?,
+            MouseButtonAction::MiddleHold => self.press("middle", 1, at).await?,
+            MouseButtonAction::LeftRelease => self.release("left", 1, at).await?,
+            MouseButtonAction::RightRelease => self.release("right", 1, at).await?,
+            MouseButtonAction::MiddleRelease => self.release("middle", 1, at).await?,
+        }
+        Ok(())
+    }
+
+    async fn click(
+        &mut self,
+        button: &'static str,
+        click_count: u8,
+        at: (i64, i64),
+        hold_ms: Option<u64>,
+    ) -> WebDriverResult<()> {
+        self.press(button, click_count, at).await?;
+        if let Some(hold_ms) = hold_ms {
+            sleep(Duration::from_millis(hold_ms)).await;
+        }
+        self.release(button, click_count, at).await
+    }
+
+    async fn press(
+        &mut self,
+        button: &'static str,
+        click_count: u8,
+        at: (i64, i64),
+    ) -> WebDriverResult<()> {
+        self.held = Some(button);
+        self.dispatch("mousePressed", button, click_count, at).await
+    }
+
+    async fn release(
+        &mut self,
+        button: &'static str,
+        click_count: u8,
+        at: (i64, i64),
+    ) -> WebDriverResult<()> {
+        self.held = None;
+        self.dispatch("mouseReleased", button, click_count, at)
+            .await
+    }
+
+    /// Moves to the viewport position in CSS pixels
+    pub(crate) async fn move_to(&self, x: i64, y: i64) -> WebDriverResult<()> {
+        self.dispatch("mouseMoved", self.held.unwrap_or("none"), 0, (x, y))
+            .await
+    }
+
+    /// Sends a mouse event at the viewport position, with the buttons held
+    /// once the event is through
+    async fn dispatch(
+        &self,
+        kind: &str,
+        button: &str,
+        click_count: u8,
+        (x, y): (i64, i64),
+    ) -> WebDriverResult<()> {
+        let buttons = match self.held {
+            Some("left") => 1,
+            Some("right") => 2,
+            Some("middle") => 4,
+            _ => 0,
+        };
+        self.devtools
+            .execute_cdp_with_params(
+                "Input.dispatchMouseEvent",
+                json!({
+                    "type": kind,
+                    "x": x,
+                    "y": y,
+                    "button": button,
+                    "buttons": buttons,
+                    "clickCount": click_count,
+                    "modifiers": self.modifiers,
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// CDP bit field of the held modifier keys
+fn modifier_flags(modifiers: &[Key]) -> u8 {
+    modifiers.iter().fold(0, |flags, key| match key {
+        Key::Alt => flags | 1,
+        Key::Control => flags | 2,
+        Key::Meta => flags | 4,
+        Key::Shift => flags | 8,
+        _ => flags,
+    })
+}
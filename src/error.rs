@@ -0,0 +1,109 @@
+use std::fmt;
+
+use thirtyfour::error::WebDriverError;
+
+use crate::MouseButtonAction;
+
+pub type MouseActionResult<T> = Result<T, MouseActionError>;
+
+#[derive(Debug)]
+pub enum MouseActionError {
+    /// The mouse position could not be read from the page
+    PositionUnavailable,
+    /// A path could not be interpolated between the given points, for zero
+    /// steps or a curve of the `spline` feature failing to build
+    Interpolation(String),
+    /// The target element has no area to land on, even after waiting for it to render
+    ZeroSizeElement,
+    /// The button action has no `ActionChain` equivalent
+    ChainUnsupported(MouseButtonAction),
+    /// The movement was cancelled before it finished
+    Cancelled,
+    /// No element matched the selector to move to
+    ElementNotFound(WebDriverError),
+    /// The target element is no longer attached to the page
+    StaleElement(WebDriverError),
+    /// Another element, described by its tag, id and classes, covers the
+    /// landing point of the target element
+    Obscured(String),
+    WebDriver(WebDriverError),
+}
+
+impl fmt::Display for MouseActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MouseActionError::PositionUnavailable => write!(f, "failed to get mouse position"),
+            MouseActionError::Interpolation(err) => write!(f, "failed to interpolate path: {err}"),
+            MouseActionError::ZeroSizeElement => write!(f, "target element has a zero size rect"),
+            MouseActionError::ChainUnsupported(action) => {
+                write!(f, "{action:?} can't be built into an action chain")
+            }
+            MouseActionError::Cancelled => write!(f, "mouse movement was cancelled"),
+            MouseActionError::ElementNotFound(err) => write!(f, "target element not found: {err}"),
+            MouseActionError::StaleElement(err) => write!(f, "target element is stale: {err}"),
+            MouseActionError::Obscured(covering) => {
+                write!(f, "target element is covered by {covering}")
+            }
+            MouseActionError::WebDriver(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for MouseActionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MouseActionError::ElementNotFound(err)
+            | MouseActionError::StaleElement(err)
+            | MouseActionError::WebDriver(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A builder setting that `build` would have to clamp or ignore
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    /// Builder method the value was given to
+    pub setting: &'static str,
+    pub reason: String,
+}
+
+impl ConfigError {
+    pub(crate) fn new(setting: &'static str, reason: impl Into<String>) -> Self {
+        ConfigError {
+            setting,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.setting, self.reason)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Error of a command on the target element, telling a stale element apart
+pub(crate) fn element_error(err: WebDriverError) -> MouseActionError {
+    match err {
+        WebDriverError::StaleElementReference(_) => MouseActionError::StaleElement(err),
+        err => MouseActionError::WebDriver(err),
+    }
+}
+
+/// Error of looking up the target element, only a missing element is
+/// reported as not found so transient errors stay retryable
+pub(crate) fn find_error(err: WebDriverError) -> MouseActionError {
+    match err {
+        WebDriverError::NoSuchElement(_) => MouseActionError::ElementNotFound(err),
+        err => element_error(err),
+    }
+}
+
+impl From<WebDriverError> for MouseActionError {
+    fn from(err: WebDriverError) -> Self {
+        MouseActionError::WebDriver(err)
+    }
+}
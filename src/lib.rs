@@ -1,22 +1,179 @@
+use std::fmt;
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use enterpolation::bezier::Bezier;
-use enterpolation::bspline::BSpline;
-use enterpolation::{easing, linear::Linear, Curve};
-use rand::{thread_rng, Rng};
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Normal};
 use thirtyfour::action_chain::ActionChain;
-use thirtyfour::error::{WebDriverError, WebDriverResult};
-use thirtyfour::{WebDriver, WebElement};
+use thirtyfour::error::WebDriverResult;
+use thirtyfour::{By, ElementRect, Key, WebDriver, WebElement};
+#[cfg(feature = "runtime-tokio")]
+use tokio_util::sync::CancellationToken;
 
-#[derive(Default, Debug, Clone)]
+#[cfg(feature = "cdp")]
+use crate::cdp::CdpMouse;
+use crate::error::{element_error, find_error};
+use crate::paths::{
+    create_asymmetric_linear_steps, create_catmull_rom_steps, create_eased_linear_steps,
+    create_linear_steps, create_spline_steps_with_range,
+};
+use crate::pointer::{PointerActions, PointerButton};
+use crate::retry::retry;
+use crate::time::{sleep, sleep_until, Duration, Instant};
+use crate::tracker::{frame_offset, mouse_position, scroll_offset};
+
+/// Stands in for the token without `runtime-tokio`, nothing being cancellable
+#[cfg(not(feature = "runtime-tokio"))]
+enum CancellationToken {}
+
+/// Debug event through `tracing` when the feature is enabled, nothing otherwise
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+/// Trace event through `tracing` when the feature is enabled, nothing otherwise
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*);
+    };
+}
+
+pub use crate::error::{ConfigError, MouseActionError, MouseActionResult};
+pub use crate::paths::PathGenerator;
+pub use crate::retry::RetryPolicy;
+
+#[cfg(feature = "cdp")]
+mod cdp;
+mod error;
+pub mod paths;
+mod pointer;
+mod retry;
+mod time;
+mod tracker;
+
+/// Serialized through [`MouseActionBuilder`], so a deserialized action is
+/// validated like a built one
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "MouseActionBuilder", into = "MouseActionBuilder")
+)]
 pub struct MouseAction {
     interpolation: MouseInterpolation,
     start_action: MouseButtonAction,
     end_action: MouseButtonAction,
+    /// Duration as requested, kept apart from the step count derived from it
     duration_ms: u64,
-    jitter_amount: i64,
+    steps: usize,
+    /// Whether `steps` was set explicitly rather than derived from the duration
+    fixed_steps: bool,
+    max_steps: usize,
+    max_pixels_per_step: Option<f64>,
+    max_speed_px_per_step: Option<f64>,
+    merge_threshold_px: Option<f64>,
+    pacing: Pacing,
+    jitter_x: i64,
+    jitter_y: i64,
+    seed: Option<u64>,
+    rng_factory: Option<RngFactory>,
+    action_time_ms: u64,
+    fps: Option<u32>,
+    precise_timing: bool,
+    clamp_to_viewport: bool,
+    scroll_into_view: bool,
+    device_pixel_scaling: bool,
+    cdp_moves: bool,
+    verify_clickable: bool,
+    overshoot: Option<f64>,
+    min_move_distance: f64,
+    landing_distribution: LandingDistribution,
+    landing_spread: f64,
+    jitter_decay: f64,
+    jitter_probability: f64,
+    jitter_distribution: JitterDistribution,
+    pointer_type: PointerType,
+    sine_wobble: Option<(f64, f64)>,
+    mid_path_pauses: Option<(usize, u64)>,
+    dwell_ms: Option<(u64, u64)>,
+    reaction_delay_ms: Option<(u64, u64)>,
+    modifiers: Vec<Key>,
+    click_hold_ms: Option<(u64, u64)>,
+    on_step: Option<StepCallback>,
+    default_start: Option<(i64, i64)>,
+    probe_position: bool,
+    retry: Option<RetryPolicy>,
+    refind_stale: bool,
+    return_to_origin: bool,
+    anchor: Anchor,
+    curve_bias: f64,
+    spline_control_range: f64,
+    easing: Easing,
+    ease_out: Option<Easing>,
+    settle: Option<Settle>,
 }
 
-#[derive(Default, Debug, Clone)]
+/// Called with every point of a movement and its index as it is sent
+#[derive(Clone)]
+struct StepCallback(Arc<dyn Fn((i64, i64), usize) + Send + Sync>);
+
+impl fmt::Debug for StepCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StepCallback")
+    }
+}
+
+/// Callbacks can't be compared, only the same callback is equal to itself
+impl PartialEq for StepCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Creates the random number generator of a movement
+#[derive(Clone)]
+struct RngFactory(Arc<dyn Fn() -> Box<dyn RngCore + Send> + Send + Sync>);
+
+impl fmt::Debug for RngFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RngFactory")
+    }
+}
+
+impl PartialEq for RngFactory {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Polling of an animated target until it stops moving
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Settle {
+    interval_ms: u64,
+    tolerance_px: f64,
+    timeout_ms: u64,
+}
+
+/// How the number of steps of a movement is chosen
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pacing {
+    /// Fixed duration for every movement
+    Duration,
+    /// Pixels per second
+    Speed(f64),
+    /// Fitts's law coefficients in milliseconds
+    Fitts { a: f64, b: f64 },
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseButtonAction {
     #[default]
     None,
@@ -24,16 +181,189 @@ pub enum MouseButtonAction {
     LeftHold,
     LeftRelease,
     RightClick,
+    RightHold,
+    RightRelease,
+    DoubleClick,
+    MiddleClick,
+    MiddleHold,
+    MiddleRelease,
 }
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseInterpolation {
     #[default]
     Linear,
     Spline,
+    /// Cubic Bézier curve, control points are fractions of the bounding box
+    /// between the start and end positions, `(0.0, 0.0)` being the start and
+    /// `(1.0, 1.0)` the end. Values outside of `0.0..=1.0` push the curve
+    /// beyond the bounding box.
+    CubicBezier {
+        c1: (f64, f64),
+        c2: (f64, f64),
+    },
+    /// Straight line following a minimum-jerk velocity profile, smooth and
+    /// reproducible as it involves no randomness
+    MinimumJerk,
+    /// WindMouse simulation, `gravity` pulls the cursor towards the target
+    /// while `wind` pushes it around randomly, classic values being 9.0 and 3.0
+    Wind {
+        gravity: f64,
+        wind: f64,
+    },
+    /// Catmull-Rom spline passing smoothly through every waypoint of
+    /// `mouse_action_path` and `drag_along_path` as a single curve, a lone
+    /// movement curving through a random point between start and end
+    CatmullRom,
+    /// User supplied path algorithm, can't be serialized
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(Arc<dyn PathGenerator>),
+}
+
+/// Custom generators can't be compared, only the same generator is equal to
+/// itself
+impl PartialEq for MouseInterpolation {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MouseInterpolation::Linear, MouseInterpolation::Linear) => true,
+            (MouseInterpolation::Spline, MouseInterpolation::Spline) => true,
+            (
+                MouseInterpolation::CubicBezier { c1, c2 },
+                MouseInterpolation::CubicBezier { c1: o1, c2: o2 },
+            ) => c1 == o1 && c2 == o2,
+            (MouseInterpolation::MinimumJerk, MouseInterpolation::MinimumJerk) => true,
+            (MouseInterpolation::CatmullRom, MouseInterpolation::CatmullRom) => true,
+            (
+                MouseInterpolation::Wind { gravity, wind },
+                MouseInterpolation::Wind {
+                    gravity: other_gravity,
+                    wind: other_wind,
+                },
+            ) => gravity == other_gravity && wind == other_wind,
+            (MouseInterpolation::Custom(a), MouseInterpolation::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// How the final point is picked within the target element
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LandingDistribution {
+    /// Equally likely anywhere within the landing spread
+    #[default]
+    Uniform,
+    /// Clustered around the middle of the element following a normal
+    /// distribution, clamped to the element bounds
+    Gaussian,
+}
+
+/// How the offset of a jittered point is drawn
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JitterDistribution {
+    /// Equally likely anywhere within the jitter amount
+    #[default]
+    Uniform,
+    /// Clustered around no offset following a normal distribution with half
+    /// the jitter amount as its standard deviation, now and then beyond it
+    Normal,
+}
+
+/// Input device the movement is performed with
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerType {
+    #[default]
+    Mouse,
+    /// A finger, holding the left button down touches the screen so a
+    /// `LeftHold` start action drags it across the page
+    Touch,
+    /// A pen, its pressure ramping from the ends of each stroke to its middle
+    Pen {
+        /// Pressure at the ends and in the middle of a stroke within
+        /// `0.0..=1.0`, `None` leaves it to the driver
+        pressure: Option<(f64, f64)>,
+        /// Barrel pressure within `-1.0..=1.0`, `0.0` for none
+        tangential_pressure: f64,
+    },
+}
+
+/// Part of the target element the landing point is picked around
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    #[default]
+    Center,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// Fractions of the width and height from the top left corner, `(0.5, 0.5)`
+    /// being the center
+    Offset {
+        x: f64,
+        y: f64,
+    },
+}
+
+impl Anchor {
+    /// Position as fractions of the width and height from the top left corner
+    fn fraction(&self) -> (f64, f64) {
+        match self {
+            Anchor::Center => (0.50, 0.50),
+            Anchor::TopLeft => (0.00, 0.00),
+            Anchor::TopRight => (1.00, 0.00),
+            Anchor::BottomLeft => (0.00, 1.00),
+            Anchor::BottomRight => (1.00, 1.00),
+            Anchor::Left => (0.00, 0.50),
+            Anchor::Right => (1.00, 0.50),
+            Anchor::Top => (0.50, 0.00),
+            Anchor::Bottom => (0.50, 1.00),
+            Anchor::Offset { x, y } => (x.clamp(0.00, 1.00), y.clamp(0.00, 1.00)),
+        }
+    }
+}
+
+/// Speed profile of linear movements, how the progress along the line follows time
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed in the middle with smoothed ends, `0.0` moving at a
+    /// constant speed throughout and `1.0` smoothing the whole movement
+    Plateau(f64),
+    /// Smoothstep, `3t² - 2t³`, accelerating and decelerating evenly
+    Smoothstep,
+    /// Half a cosine wave, `(1 - cos(πt)) / 2`
+    Sine,
+    /// Accelerates from rest and arrives at full speed, `t²`
+    EaseIn,
+    /// Leaves at full speed and decelerates onto the target, `1 - (1 - t)²`
+    EaseOut,
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Plateau(0.10)
+    }
+}
+
+impl Default for MouseAction {
+    fn default() -> Self {
+        MouseAction::builder().build()
+    }
 }
 
 impl MouseAction {
+    /// Positional constructor kept for backwards compatibility.
+    ///
+    /// Prefer [`MouseAction::builder`], which names every argument at the call site.
+    #[deprecated(note = "use `MouseAction::builder()` instead")]
     pub fn new(
         interpolation: MouseInterpolation,
         start_action: MouseButtonAction,
@@ -41,247 +371,3616 @@ impl MouseAction {
         duration_ms: Option<u64>,
         jitter_amount: Option<i64>,
     ) -> Self {
-        let jitter_amount = jitter_amount.unwrap_or(0);
-        let mut duration_ms = duration_ms.unwrap_or(500);
+        let mut builder = MouseAction::builder()
+            .interpolation(interpolation)
+            .start_action(start_action)
+            .end_action(end_action);
 
-        // Each Action takes between 5-9ms with it averaging out to 7ms
-        let divider = 7;
-        if duration_ms < divider {
-            duration_ms = 1;
-        } else {
-            duration_ms /= divider;
+        if let Some(duration_ms) = duration_ms {
+            builder = builder.duration_ms(duration_ms);
         }
-
-        MouseAction {
-            interpolation,
-            start_action,
-            end_action,
-            duration_ms,
-            jitter_amount,
+        if let Some(jitter_amount) = jitter_amount {
+            builder = builder.jitter_amount(jitter_amount);
         }
+
+        builder.build()
     }
-}
 
-#[async_trait]
-pub trait MouseActionExt {
-    async fn mouse_action(
-        &self,
-        action: MouseAction,
-        target_element: &WebElement,
-    ) -> WebDriverResult<()>;
-}
+    pub fn builder() -> MouseActionBuilder {
+        MouseActionBuilder::default()
+    }
 
-#[async_trait]
-impl MouseActionExt for WebDriver {
-    /// Simulate mouse movement across a path over a duration
+    /// Believable everyday movement, a spline with moderate jitter steadying
+    /// near the target, a reaction delay, a short dwell and held clicks
     ///
-    /// Note: There is no guarantee the duration is exact, but should be close
-    async fn mouse_action(
-        &self,
-        action: MouseAction,
-        target_element: &WebElement,
-    ) -> WebDriverResult<()> {
-        let mouse_x_ret = self
-            .execute(r#"return window.tf_m_mouse_x || -1;"#, Vec::new())
-            .await?;
-        let mut mouse_x = mouse_x_ret.convert::<i64>()?;
+    /// Presets return a builder, so any setting can still be tweaked before `build`
+    pub fn human() -> MouseActionBuilder {
+        MouseAction::builder()
+            .interpolation(MouseInterpolation::Spline)
+            .duration_ms(600)
+            .jitter_amount(2)
+            .jitter_decay(0.30)
+            .landing_distribution(LandingDistribution::Gaussian)
+            .reaction_delay_range_ms(200, 300)
+            .dwell_range_ms(50, 150)
+            .click_hold_ms(50, 120)
+    }
 
-        let mouse_y_ret = self
-            .execute(r#"return window.tf_m_mouse_y || -1;"#, Vec::new())
-            .await?;
-        let mut mouse_y = mouse_y_ret.convert::<i64>()?;
-
-        if mouse_x <= -1 || mouse_y <= -1 {
-            self.execute(
-                r#"
-                window.tf_m_mouse_x = window.tf_m_mouse_x || -1;
-                window.tf_m_mouse_y = window.tf_m_mouse_y || -1;
-
-                document.addEventListener("mousemove", (event) => {
-                   window.tf_m_mouse_x = event.clientX;
-                   window.tf_m_mouse_y = event.clientY;
-                });"#,
-                Vec::new(),
-            )
-            .await?;
+    /// Quick and direct movement with barely any jitter and no delays
+    pub fn fast() -> MouseActionBuilder {
+        MouseAction::builder()
+            .interpolation(MouseInterpolation::MinimumJerk)
+            .duration_ms(250)
+            .jitter_amount(1)
+    }
 
-            self.action_chain().move_by_offset(1, 1).perform().await?;
+    /// Slow and deliberate movement landing close to the middle of the
+    /// target, with long pauses before moving and clicking
+    pub fn careful() -> MouseActionBuilder {
+        MouseAction::builder()
+            .interpolation(MouseInterpolation::Spline)
+            .duration_ms(1_200)
+            .jitter_amount(1)
+            .jitter_decay(0.50)
+            .landing_distribution(LandingDistribution::Gaussian)
+            .landing_spread(0.30)
+            .reaction_delay_range_ms(300, 500)
+            .dwell_range_ms(150, 300)
+            .click_hold_ms(80, 150)
+    }
 
-            let mouse_x_ret = self
-                .execute(r#"return window.tf_m_mouse_x || -1;"#, Vec::new())
-                .await?;
-            mouse_x = mouse_x_ret.convert::<i64>()?;
+    /// Straight, short and exact movement to the middle of the target
+    pub fn robotic() -> MouseActionBuilder {
+        MouseAction::builder()
+            .interpolation(MouseInterpolation::Linear)
+            .duration_ms(100)
+            .jitter_amount(0)
+            .landing_spread(0.00)
+    }
 
-            let mouse_y_ret = self
-                .execute(r#"return window.tf_m_mouse_y || -1;"#, Vec::new())
-                .await?;
-            mouse_y = mouse_y_ret.convert::<i64>()?;
+    pub fn interpolation(&self) -> &MouseInterpolation {
+        &self.interpolation
+    }
 
-            if mouse_x <= -1 || mouse_y <= -1 {
-                return Err(WebDriverError::CommandRecvError(
-                    "Failed to get mouse position".to_string(),
-                ));
-            }
+    pub fn start_action(&self) -> &MouseButtonAction {
+        &self.start_action
+    }
+
+    pub fn end_action(&self) -> &MouseButtonAction {
+        &self.end_action
+    }
+
+    /// Requested duration of a movement paced by duration, or the step count
+    /// times the time per step when the steps were set explicitly
+    pub fn duration_ms(&self) -> u64 {
+        if self.fixed_steps {
+            (self.steps as f64 * self.step_time_ms()).ceil() as u64
+        } else {
+            self.duration_ms
         }
+    }
 
-        let target_rect = target_element.rect().await?;
+    /// Number of steps of a movement paced by duration, speed and Fitts's law
+    /// derive it from each movement instead
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
 
-        let half_width = (target_rect.width / 2.00) as i64;
-        let half_height = (target_rect.height / 2.00) as i64;
-        let target_pos_x = target_rect.x as i64 + half_width; // Middle of element
-        let target_pos_y = target_rect.y as i64 + half_height; // Middle of element
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
 
-        let quarter_width = half_width / 2;
-        let quarter_height = half_height / 2;
-        let final_pos_x = target_pos_x + thread_rng().gen_range(-quarter_width..=quarter_width);
-        let final_pos_y = target_pos_y + thread_rng().gen_range(-quarter_height..=quarter_height);
+    pub fn max_speed_px_per_step(&self) -> Option<f64> {
+        self.max_speed_px_per_step
+    }
 
-        let mut positions = match &action.interpolation {
-            MouseInterpolation::Linear => create_linear_steps(
-                mouse_x,
-                mouse_y,
-                final_pos_x,
-                final_pos_y,
-                action.duration_ms as usize,
-            ),
-            MouseInterpolation::Spline => create_spline_steps(
-                mouse_x,
-                mouse_y,
-                final_pos_x,
-                final_pos_y,
-                action.duration_ms as usize,
-            ),
+    pub fn merge_threshold_px(&self) -> Option<f64> {
+        self.merge_threshold_px
+    }
+
+    /// Jitter on both axes as `(x, y)`, the same on both when set with
+    /// `jitter_amount`
+    pub fn jitter_amount(&self) -> (i64, i64) {
+        (self.jitter_x, self.jitter_y)
+    }
+
+    pub fn jitter_x(&self) -> i64 {
+        self.jitter_x
+    }
+
+    pub fn jitter_y(&self) -> i64 {
+        self.jitter_y
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn fps(&self) -> Option<u32> {
+        self.fps
+    }
+
+    pub fn precise_timing(&self) -> bool {
+        self.precise_timing
+    }
+
+    pub fn landing_distribution(&self) -> &LandingDistribution {
+        &self.landing_distribution
+    }
+
+    pub fn anchor(&self) -> &Anchor {
+        &self.anchor
+    }
+
+    pub fn easing(&self) -> &Easing {
+        &self.easing
+    }
+
+    pub fn spline_control_range(&self) -> f64 {
+        self.spline_control_range
+    }
+
+    pub fn pointer_type(&self) -> PointerType {
+        self.pointer_type
+    }
+
+    /// Number of pauses along the path and their maximum duration
+    pub fn mid_path_pauses(&self) -> Option<(usize, u64)> {
+        self.mid_path_pauses
+    }
+
+    /// Range of the pause on the target before the end action
+    pub fn dwell_ms(&self) -> Option<(u64, u64)> {
+        self.dwell_ms
+    }
+
+    /// Range of the pause before the movement starts
+    pub fn reaction_delay_ms(&self) -> Option<(u64, u64)> {
+        self.reaction_delay_ms
+    }
+
+    /// Range of the time the button stays down during a click
+    pub fn click_hold_ms(&self) -> Option<(u64, u64)> {
+        self.click_hold_ms
+    }
+
+    pub fn modifiers(&self) -> &[Key] {
+        &self.modifiers
+    }
+
+    pub fn retry(&self) -> Option<&RetryPolicy> {
+        self.retry.as_ref()
+    }
+
+    /// Whether the step count was set explicitly rather than derived from the
+    /// duration
+    pub fn fixed_steps(&self) -> bool {
+        self.fixed_steps
+    }
+
+    pub fn max_pixels_per_step(&self) -> Option<f64> {
+        self.max_pixels_per_step
+    }
+
+    pub fn pacing(&self) -> &Pacing {
+        &self.pacing
+    }
+
+    pub fn action_time_ms(&self) -> u64 {
+        self.action_time_ms
+    }
+
+    pub fn clamp_to_viewport(&self) -> bool {
+        self.clamp_to_viewport
+    }
+
+    pub fn scroll_into_view(&self) -> bool {
+        self.scroll_into_view
+    }
+
+    pub fn device_pixel_scaling(&self) -> bool {
+        self.device_pixel_scaling
+    }
+
+    pub fn cdp_moves(&self) -> bool {
+        self.cdp_moves
+    }
+
+    pub fn verify_clickable(&self) -> bool {
+        self.verify_clickable
+    }
+
+    pub fn overshoot(&self) -> Option<f64> {
+        self.overshoot
+    }
+
+    pub fn min_move_distance(&self) -> f64 {
+        self.min_move_distance
+    }
+
+    pub fn landing_spread(&self) -> f64 {
+        self.landing_spread
+    }
+
+    pub fn jitter_decay(&self) -> f64 {
+        self.jitter_decay
+    }
+
+    pub fn jitter_probability(&self) -> f64 {
+        self.jitter_probability
+    }
+
+    pub fn jitter_distribution(&self) -> &JitterDistribution {
+        &self.jitter_distribution
+    }
+
+    /// Amplitude and frequency of the sway along the path
+    pub fn sine_wobble(&self) -> Option<(f64, f64)> {
+        self.sine_wobble
+    }
+
+    pub fn default_start(&self) -> Option<(i64, i64)> {
+        self.default_start
+    }
+
+    pub fn probe_position(&self) -> bool {
+        self.probe_position
+    }
+
+    pub fn refind_stale(&self) -> bool {
+        self.refind_stale
+    }
+
+    pub fn return_to_origin(&self) -> bool {
+        self.return_to_origin
+    }
+
+    pub fn curve_bias(&self) -> f64 {
+        self.curve_bias
+    }
+
+    /// Easing of the end of linear paths when it differs from the start
+    pub fn ease_out(&self) -> Option<&Easing> {
+        self.ease_out.as_ref()
+    }
+
+    /// Interval, tolerance and timeout of the wait for a stable rect
+    pub fn settle(&self) -> Option<(u64, f64, u64)> {
+        self.settle
+            .as_ref()
+            .map(|settle| (settle.interval_ms, settle.tolerance_px, settle.timeout_ms))
+    }
+
+    /// Copy of this action with another approximate duration
+    pub fn with_duration_ms(self, duration_ms: u64) -> Self {
+        MouseActionBuilder::from(self)
+            .duration_ms(duration_ms)
+            .build()
+    }
+
+    /// Copy of this action with another jitter amount on both axes
+    pub fn with_jitter(self, jitter_amount: i64) -> Self {
+        MouseActionBuilder::from(self)
+            .jitter_amount(jitter_amount)
+            .build()
+    }
+
+    /// Copy of this action with another path algorithm
+    pub fn with_interpolation(self, interpolation: MouseInterpolation) -> Self {
+        MouseActionBuilder::from(self)
+            .interpolation(interpolation)
+            .build()
+    }
+
+    /// Copy of this action with another action before moving
+    pub fn with_start_action(self, start_action: MouseButtonAction) -> Self {
+        MouseActionBuilder::from(self)
+            .start_action(start_action)
+            .build()
+    }
+
+    /// Copy of this action with another action on the target
+    pub fn with_end_action(self, end_action: MouseButtonAction) -> Self {
+        MouseActionBuilder::from(self)
+            .end_action(end_action)
+            .build()
+    }
+
+    /// Copy of this action with another seed
+    pub fn with_seed(self, seed: u64) -> Self {
+        MouseActionBuilder::from(self).seed(seed).build()
+    }
+
+    /// Copy of this action with any other setting changed through its builder,
+    /// for settings without a `with_*` shorthand
+    pub fn with(self, tweak: impl FnOnce(MouseActionBuilder) -> MouseActionBuilder) -> Self {
+        tweak(MouseActionBuilder::from(self)).build()
+    }
+
+    /// Number of steps for a movement between two points, from the distance
+    /// and target size when not moving for a fixed duration
+    fn steps_between(
+        &self,
+        start: (i64, i64),
+        end: (i64, i64),
+        target: Option<&ElementRect>,
+    ) -> usize {
+        let distance = ((end.0 - start.0) as f64).hypot((end.1 - start.1) as f64);
+        let steps = match self.pacing {
+            Pacing::Duration => self.steps,
+            Pacing::Speed(speed) => (distance / speed * 1000.00 / self.step_time_ms()) as usize,
+            Pacing::Fitts { a, b } => {
+                // A point has no size, count it as a single pixel
+                let width = target.map_or(1.00, |rect| rect.width.min(rect.height).max(1.00));
+                ((a + b * (distance / width + 1.00).log2()) / self.step_time_ms()) as usize
+            }
         };
+        let dense_steps = self
+            .max_pixels_per_step
+            .map_or(0, |pixels| (distance / pixels).ceil() as usize);
+        steps.max(dense_steps).clamp(1, self.max_steps)
+    }
 
-        if action.jitter_amount > 0 {
-            jitter(&mut positions, action.jitter_amount);
+    /// Time between two moves, from the frame rate when set or the estimated
+    /// driver time per move otherwise
+    fn step_time_ms(&self) -> f64 {
+        match self.fps {
+            Some(fps) => 1000.00 / fps as f64,
+            None => self.action_time_ms as f64,
         }
+    }
 
-        let action_chain = self.action_chain_with_delay(None, Some(0));
-        let mut action_chain = action
-            .start_action
-            .action(action_chain);
-
-        for point in positions {
-            action_chain = action_chain.move_to(point.0, point.1);
+    /// Time between two moves when timed precisely, spreading the requested
+    /// duration over the steps so rounding the step count doesn't shorten it
+    fn paced_step_time_ms(&self) -> f64 {
+        match self.pacing {
+            Pacing::Duration if !self.fixed_steps => self.duration_ms as f64 / self.steps as f64,
+            _ => self.step_time_ms(),
         }
+    }
 
-        action.end_action.action(action_chain).perform().await?;
+    /// Indices of the points to pause after, never the last one so the
+    /// pauses happen on the way rather than on the target
+    fn pause_points(&self, strokes: &[Stroke], rng: &mut impl Rng) -> Vec<usize> {
+        let Some((count, _)) = self.mid_path_pauses else {
+            return Vec::new();
+        };
+        let candidates = strokes
+            .iter()
+            .map(|stroke| stroke.positions.len())
+            .sum::<usize>()
+            .saturating_sub(1);
+        rand::seq::index::sample(rng, candidates, count.min(candidates)).into_vec()
+    }
 
-        Ok(())
+    /// Random length of a pause along the path
+    fn mid_path_pause(&self, rng: &mut impl Rng) -> Duration {
+        let max_ms = self.mid_path_pauses.map_or(0, |(_, max_ms)| max_ms);
+        random_delay(Some((1, max_ms)), rng)
     }
-}
 
-impl MouseButtonAction {
-    fn action(&self, action_chain: ActionChain) -> ActionChain {
-        match self {
-            MouseButtonAction::None => action_chain,
-            MouseButtonAction::LeftClick => action_chain.click(),
-            MouseButtonAction::LeftHold => action_chain.click_and_hold(),
-            MouseButtonAction::LeftRelease => action_chain.release(),
-            MouseButtonAction::RightClick => action_chain.context_click(),
+    /// Pause between arriving on the target and the end action
+    fn dwell(&self, rng: &mut impl Rng) -> Duration {
+        random_delay(self.dwell_ms, rng)
+    }
+
+    /// Time the button stays down during a click, `None` for an instant click
+    fn click_hold(&self, rng: &mut impl Rng) -> Option<u64> {
+        self.click_hold_ms
+            .map(|(min_ms, max_ms)| rng.gen_range(min_ms..=max_ms))
+    }
+
+    /// Reports a point being sent to the step callback
+    fn step(&self, point: (i64, i64), index: usize) {
+        if let Some(on_step) = &self.on_step {
+            (on_step.0)(point, index);
         }
     }
-}
 
-fn jitter(input: &mut [(i64, i64)], amount: i64) {
-    input.iter_mut().for_each(|(x, y)| {
-        let add_jitter = thread_rng().gen_bool(1.00 / 5.00);
-        if add_jitter {
-            *x += thread_rng().gen_range(-amount..=amount);
-            *y += thread_rng().gen_range(-amount..=amount);
+    /// Pause before reading the mouse position and starting to move
+    fn reaction_delay(&self, rng: &mut impl Rng) -> Duration {
+        random_delay(self.reaction_delay_ms, rng)
+    }
+
+    /// Random number generator driving every random choice of the movement,
+    /// from the factory when one was given, seeded when a seed was configured
+    /// so the same path can be reproduced
+    fn rng(&self) -> Box<dyn RngCore + Send> {
+        match (&self.rng_factory, self.seed) {
+            (Some(factory), _) => (factory.0)(),
+            (None, Some(seed)) => Box::new(ChaCha8Rng::seed_from_u64(seed)),
+            (None, None) => Box::new(ChaCha8Rng::from_entropy()),
         }
-    })
+    }
 }
 
-fn create_spline_steps(
-    start_x: i64,
-    start_y: i64,
-    end_x: i64,
-    end_y: i64,
-    steps: usize,
-) -> Vec<(i64, i64)> {
-    let x_min = start_x.min(end_x);
-    let x_max = start_x.max(end_x);
-    let y_min = start_y.min(end_y);
-    let y_max = start_y.max(end_y);
-
-    let mut rng = thread_rng();
-    let x_offset_one = rng.gen_range(x_min..x_max);
-    let y_offset_one = rng.gen_range(y_min..y_max);
-
-    let linear_x = Linear::builder()
-        .elements([start_x as f64, x_offset_one as f64, end_x as f64])
-        .equidistant()
-        .normalized()
-        .easing(easing::Plateau::new(0.00))
-        .build()
-        .unwrap();
+/// Concise summary for logs, e.g. `Spline 500ms, jitter 3, LeftClick`
+impl fmt::Display for MouseAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let interpolation = match self.interpolation {
+            MouseInterpolation::Linear => "Linear",
+            MouseInterpolation::Spline => "Spline",
+            MouseInterpolation::CubicBezier { .. } => "CubicBezier",
+            MouseInterpolation::MinimumJerk => "MinimumJerk",
+            MouseInterpolation::Wind { .. } => "Wind",
+            MouseInterpolation::CatmullRom => "CatmullRom",
+            MouseInterpolation::Custom(_) => "Custom",
+        };
+        write!(f, "{interpolation} {}ms", self.duration_ms())?;
 
-    let bezier_y = Bezier::builder()
-        .elements([start_y as f64, y_offset_one as f64, end_y as f64])
-        .normalized::<f64>()
-        .constant::<3>()
-        .build()
-        .unwrap();
+        match (self.jitter_x, self.jitter_y) {
+            (0, 0) => {}
+            (x, y) if x == y => write!(f, ", jitter {x}")?,
+            (x, y) => write!(f, ", jitter {x}x{y}")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, ", seed {seed}")?;
+        }
 
-    let bspline_y = BSpline::builder()
-        .clamped()
-        .elements([start_y as f64, y_offset_one as f64, end_y as f64])
-        .knots(bezier_y.domain())
-        .dynamic()
-        .build()
-        .unwrap();
+        match self.start_action {
+            MouseButtonAction::None => write!(f, ", {:?}", self.end_action),
+            ref start => write!(f, ", {start:?} then {:?}", self.end_action),
+        }
+    }
+}
 
-    linear_x
-        .take(steps)
-        .zip(bspline_y.take(steps))
-        .map(|(mut x, mut y)| {
-            if x.is_sign_negative() {
-                x = 0.00;
-            }
-            if y.is_sign_negative() {
-                y = 0.00;
-            }
-            (x as i64, y as i64)
-        })
-        .collect::<Vec<_>>()
+/// Missing fields fall back to their defaults when deserializing
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct MouseActionBuilder {
+    interpolation: MouseInterpolation,
+    start_action: MouseButtonAction,
+    end_action: MouseButtonAction,
+    duration_ms: u64,
+    steps: Option<usize>,
+    max_steps: usize,
+    max_pixels_per_step: Option<f64>,
+    max_speed_px_per_step: Option<f64>,
+    merge_threshold_px: Option<f64>,
+    pacing: Pacing,
+    jitter_x: i64,
+    jitter_y: i64,
+    seed: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng_factory: Option<RngFactory>,
+    action_time_ms: u64,
+    fps: Option<u32>,
+    precise_timing: bool,
+    clamp_to_viewport: bool,
+    scroll_into_view: bool,
+    device_pixel_scaling: bool,
+    cdp_moves: bool,
+    verify_clickable: bool,
+    overshoot: Option<f64>,
+    min_move_distance: f64,
+    landing_distribution: LandingDistribution,
+    landing_spread: f64,
+    jitter_decay: f64,
+    jitter_probability: f64,
+    jitter_distribution: JitterDistribution,
+    pointer_type: PointerType,
+    sine_wobble: Option<(f64, f64)>,
+    mid_path_pauses: Option<(usize, u64)>,
+    dwell_ms: Option<(u64, u64)>,
+    reaction_delay_ms: Option<(u64, u64)>,
+    // thirtyfour keys can't be serialized
+    #[cfg_attr(feature = "serde", serde(skip))]
+    modifiers: Vec<Key>,
+    click_hold_ms: Option<(u64, u64)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    on_step: Option<StepCallback>,
+    default_start: Option<(i64, i64)>,
+    probe_position: bool,
+    retry: Option<RetryPolicy>,
+    refind_stale: bool,
+    return_to_origin: bool,
+    anchor: Anchor,
+    curve_bias: f64,
+    spline_control_range: f64,
+    easing: Easing,
+    ease_out: Option<Easing>,
+    settle: Option<Settle>,
 }
 
-fn create_linear_steps(
-    start_x: i64,
-    start_y: i64,
-    end_x: i64,
-    end_y: i64,
-    steps: usize,
-) -> Vec<(i64, i64)> {
-    let linear_x = Linear::builder()
-        .elements([start_x as f64, end_x as f64])
-        .equidistant::<f64>()
-        .normalized()
-        .easing(easing::Plateau::new(0.1))
-        .build()
-        .unwrap();
+impl Default for MouseActionBuilder {
+    fn default() -> Self {
+        MouseActionBuilder {
+            interpolation: MouseInterpolation::default(),
+            start_action: MouseButtonAction::default(),
+            end_action: MouseButtonAction::default(),
+            duration_ms: 500,
+            max_steps: 5_000,
+            max_pixels_per_step: None,
+            max_speed_px_per_step: None,
+            merge_threshold_px: None,
+            steps: None,
+            pacing: Pacing::Duration,
+            jitter_x: 0,
+            jitter_y: 0,
+            seed: None,
+            rng_factory: None,
+            // Each Action takes between 5-9ms with it averaging out to 7ms
+            action_time_ms: 7,
+            fps: None,
+            precise_timing: false,
+            clamp_to_viewport: true,
+            scroll_into_view: true,
+            device_pixel_scaling: false,
+            cdp_moves: false,
+            verify_clickable: false,
+            overshoot: None,
+            min_move_distance: 0.00,
+            landing_distribution: LandingDistribution::default(),
+            landing_spread: 0.50,
+            jitter_decay: 0.00,
+            jitter_probability: 1.00 / 5.00,
+            jitter_distribution: JitterDistribution::default(),
+            pointer_type: PointerType::default(),
+            sine_wobble: None,
+            mid_path_pauses: None,
+            dwell_ms: None,
+            reaction_delay_ms: None,
+            modifiers: Vec::new(),
+            click_hold_ms: None,
+            on_step: None,
+            default_start: None,
+            probe_position: true,
+            retry: None,
+            refind_stale: false,
+            return_to_origin: false,
+            anchor: Anchor::default(),
+            curve_bias: 0.00,
+            spline_control_range: 1.00,
+            easing: Easing::default(),
+            ease_out: None,
+            settle: None,
+        }
+    }
+}
 
-    let linear_y = Linear::builder()
-        .elements([start_y as f64, end_y as f64])
-        .equidistant::<f64>()
-        .normalized()
-        .easing(easing::Plateau::new(0.1))
-        .build()
-        .unwrap();
+impl MouseActionBuilder {
+    pub fn interpolation(mut self, interpolation: MouseInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
 
-    linear_x
-        .take(steps)
-        .zip(linear_y.take(steps))
-        .map(|(mut x, mut y)| {
-            if x.is_sign_negative() {
-                x = 0.00;
-            }
-            if y.is_sign_negative() {
-                y = 0.00;
+    /// Button action performed at the starting position, before any movement
+    ///
+    /// Independent of the end action, any pair works: a `LeftClick` start and a
+    /// `RightClick` end click at the origin and right click at the target, a
+    /// `LeftHold` start and a `LeftRelease` end drag, a `None` start and a
+    /// `DoubleClick` end only click at the target. A button held here stays
+    /// pressed along the whole path until released.
+    pub fn start_action(mut self, start_action: MouseButtonAction) -> Self {
+        self.start_action = start_action;
+        self
+    }
+
+    /// Button action performed at the final position, after the movement
+    ///
+    /// Clicking the same button at both ends of a very short movement may be
+    /// read by the browser as a double click
+    pub fn end_action(mut self, end_action: MouseButtonAction) -> Self {
+        self.end_action = end_action;
+        self
+    }
+
+    /// Approximate duration of the movement, defaults to 500ms
+    pub fn duration_ms(mut self, duration_ms: u64) -> Self {
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    /// Exact number of steps in the path, overriding the count derived from
+    /// the duration
+    ///
+    /// Setting this ignores `duration_ms`, the movement then takes roughly
+    /// `steps * action_time_ms`, or `steps / fps` seconds with a frame rate
+    pub fn steps(mut self, steps: usize) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Upper bound of the step count however it is derived, defaults to 5000
+    ///
+    /// Keeps a huge duration or a crawling speed from allocating an enormous
+    /// path, the movement then goes quicker than asked
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Longest distance covered by a single move, adding steps to long
+    /// movements whose duration or speed would otherwise jump across them
+    ///
+    /// Still bounded by `max_steps`
+    pub fn max_pixels_per_step(mut self, max_pixels_per_step: f64) -> Self {
+        self.max_pixels_per_step = Some(max_pixels_per_step);
+        self
+    }
+
+    /// Hard limit on the distance between two consecutive points of the final
+    /// path, splitting faster segments with extra points in between
+    ///
+    /// Unlike `max_pixels_per_step` it applies after wobble and jitter, so they
+    /// can't cause spikes, and isn't bounded by `max_steps`. Every extra
+    /// point is one more step, lengthening the movement.
+    pub fn max_speed_px_per_step(mut self, max_speed_px_per_step: f64) -> Self {
+        self.max_speed_px_per_step = Some(max_speed_px_per_step);
+        self
+    }
+
+    /// Drop points closer than this many pixels to the previous one, so eased
+    /// ends don't send a run of moves that barely go anywhere
+    ///
+    /// The last point is always kept to land on the target. Every dropped point
+    /// is one step less, shortening the movement.
+    pub fn merge_threshold_px(mut self, merge_threshold_px: f64) -> Self {
+        self.merge_threshold_px = Some(merge_threshold_px);
+        self
+    }
+
+    /// Move at this many pixels per second instead of a fixed duration, so
+    /// the duration of each movement follows its distance
+    ///
+    /// Takes precedence over `duration_ms` and replaces Fitts's law, an
+    /// explicit step count takes precedence over all of them
+    pub fn speed_px_per_s(mut self, speed: f64) -> Self {
+        self.pacing = Pacing::Speed(speed);
+        self
+    }
+
+    /// Derive the duration of each movement from Fitts's law,
+    /// `a + b * log2(distance / size + 1)` in milliseconds, so small far
+    /// targets take longer to reach than large near ones
+    ///
+    /// The size is the smaller side of the target element, replaces a set speed
+    pub fn fitts_law(mut self, a: f64, b: f64) -> Self {
+        self.pacing = Pacing::Fitts { a, b };
+        self
+    }
+
+    /// Maximum pixel offset applied to points along the path on both axes, 0
+    /// disables jitter
+    ///
+    /// Negative amounts are treated as 0 on every jitter setter
+    pub fn jitter_amount(mut self, jitter_amount: i64) -> Self {
+        self.jitter_x = jitter_amount;
+        self.jitter_y = jitter_amount;
+        self
+    }
+
+    /// Maximum horizontal pixel offset applied to points along the path, 0
+    /// disables it
+    pub fn jitter_x(mut self, jitter_x: i64) -> Self {
+        self.jitter_x = jitter_x;
+        self
+    }
+
+    /// Maximum vertical pixel offset applied to points along the path, 0
+    /// disables it
+    pub fn jitter_y(mut self, jitter_y: i64) -> Self {
+        self.jitter_y = jitter_y;
+        self
+    }
+
+    /// Fraction of the path at its end over which the jitter shrinks linearly
+    /// down to nothing, so the cursor steadies as it reaches the target
+    ///
+    /// Defaults to 0.0, the same jitter along the whole path
+    pub fn jitter_decay(mut self, jitter_decay: f64) -> Self {
+        self.jitter_decay = jitter_decay;
+        self
+    }
+
+    /// Chance of every point along the path to be jittered, clamped to `0.0..=1.0`
+    ///
+    /// Defaults to 0.2
+    pub fn jitter_probability(mut self, jitter_probability: f64) -> Self {
+        self.jitter_probability = jitter_probability;
+        self
+    }
+
+    /// Distribution of the jitter offsets, defaults to uniform
+    pub fn jitter_distribution(mut self, jitter_distribution: JitterDistribution) -> Self {
+        self.jitter_distribution = jitter_distribution;
+        self
+    }
+
+    /// Device to move with, defaults to the mouse
+    ///
+    /// Touch and pen input is sent as raw pointer actions, action chains built
+    /// with `build_mouse_action_chain` always drive the mouse
+    pub fn pointer_type(mut self, pointer_type: PointerType) -> Self {
+        self.pointer_type = pointer_type;
+        self
+    }
+
+    /// Sway the path perpendicular to the direction of travel by
+    /// `amplitude * sin(2π * frequency * t)` pixels, `t` being the seconds
+    /// since the movement started
+    ///
+    /// A smooth hand oscillation, applied on top of any jitter, the last point
+    /// still lands on the target
+    pub fn sine_wobble(mut self, amplitude: f64, frequency: f64) -> Self {
+        self.sine_wobble = Some((amplitude, frequency));
+        self
+    }
+
+    /// Hesitate `count` times at random points along the path, each pause
+    /// lasting up to `max_ms`
+    ///
+    /// The moves are then paced like precise timing so the pauses can happen
+    /// between them, adding to the movement duration. Action chains built
+    /// with `build_mouse_action_chain` can't pause and ignore it.
+    pub fn mid_path_pauses(mut self, count: usize, max_ms: u64) -> Self {
+        self.mid_path_pauses = Some((count, max_ms));
+        self
+    }
+
+    /// Pause on the target for this long before performing the end action
+    pub fn dwell_ms(mut self, dwell_ms: u64) -> Self {
+        self.dwell_ms = Some((dwell_ms, dwell_ms));
+        self
+    }
+
+    /// Pause on the target for a random duration within the range before
+    /// performing the end action
+    pub fn dwell_range_ms(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.dwell_ms = Some((min_ms.min(max_ms), min_ms.max(max_ms)));
+        self
+    }
+
+    /// Wait this long before reading the mouse position and starting to
+    /// move, independent of the movement duration
+    pub fn reaction_delay_ms(mut self, reaction_delay_ms: u64) -> Self {
+        self.reaction_delay_ms = Some((reaction_delay_ms, reaction_delay_ms));
+        self
+    }
+
+    /// Wait a random duration within the range before reading the mouse
+    /// position and starting to move
+    pub fn reaction_delay_range_ms(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.reaction_delay_ms = Some((min_ms.min(max_ms), min_ms.max(max_ms)));
+        self
+    }
+
+    /// Modifier keys held down for the whole movement, pressed before the
+    /// start action and released after the end action, such as `Key::Shift`
+    /// for a shift click
+    pub fn modifiers(mut self, modifiers: Vec<Key>) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Hold the button down for a random duration within the range between
+    /// the press and the release of every click, instead of an instant click
+    ///
+    /// Clicks with a hold are sent as raw pointer actions
+    pub fn click_hold_ms(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.click_hold_ms = Some((min_ms.min(max_ms), min_ms.max(max_ms)));
+        self
+    }
+
+    /// Called with every point and its index along the whole movement as it
+    /// is sent, to follow the progress or record the path
+    ///
+    /// Without precise timing the points are sent in one batch, so the
+    /// callback runs as each point is queued rather than as the mouse gets there
+    pub fn on_step(mut self, on_step: impl Fn((i64, i64), usize) + Send + Sync + 'static) -> Self {
+        self.on_step = Some(StepCallback(Arc::new(on_step)));
+        self
+    }
+
+    /// Where the mouse is moved to before the movement when its position
+    /// can't be found, defaults to the middle of the viewport
+    pub fn default_start(mut self, x: i64, y: i64) -> Self {
+        self.default_start = Some((x, y));
+        self
+    }
+
+    /// Look for an unknown mouse position by moving it a pixel, enabled by default
+    ///
+    /// Disabled, nothing moves before the movement which then starts from
+    /// `default_start` or the middle of the viewport, its first move jumping
+    /// there from wherever the mouse really is
+    pub fn probe_position(mut self, probe_position: bool) -> Self {
+        self.probe_position = probe_position;
+        self
+    }
+
+    /// Retry reading the mouse position and sending the moves after transient
+    /// WebDriver errors, such as timeouts on a busy grid
+    ///
+    /// Other errors, like a missing element, are returned right away
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Find the target again with its selector and retry once when it went
+    /// stale before the movement started
+    ///
+    /// Only `mouse_action_by` knows the selector, other movements return
+    /// [`MouseActionError::StaleElement`]
+    pub fn refind_stale(mut self, refind_stale: bool) -> Self {
+        self.refind_stale = refind_stale;
+        self
+    }
+
+    /// Move back to where the movement started once the end action is done,
+    /// along a path with the same interpolation and jitter
+    ///
+    /// The returned points include the way back, `build_mouse_action_chain`
+    /// leaves the mouse on the target
+    pub fn return_to_origin(mut self, return_to_origin: bool) -> Self {
+        self.return_to_origin = return_to_origin;
+        self
+    }
+
+    /// Part of the target element to land on, the landing spread applies
+    /// around it, defaults to the center
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Bow the path sideways in a consistent direction by this fraction of the
+    /// distance at its midpoint, positive arcs to the left of the direction of
+    /// travel and negative to the right
+    ///
+    /// Gives linear paths an organic arc without the randomness of a spline,
+    /// only [`MouseInterpolation::Linear`] is bowed as the other modes shape
+    /// their own curve
+    pub fn curve_bias(mut self, curve_bias: f64) -> Self {
+        self.curve_bias = curve_bias;
+        self
+    }
+
+    /// Fraction of the bounding box between start and end the random control
+    /// point of [`MouseInterpolation::Spline`] is drawn from, centered on it
+    ///
+    /// Defaults to `1.0`, the whole box, `0.6` keeps it within the middle 60%
+    /// for gentler arcs while values above `1.0` exaggerate them on the y axis,
+    /// x always progressing towards the target
+    pub fn spline_control_range(mut self, spline_control_range: f64) -> Self {
+        self.spline_control_range = spline_control_range;
+        self
+    }
+
+    /// Speed profile of [`MouseInterpolation::Linear`] movements, defaults to
+    /// `Easing::Plateau(0.1)`
+    ///
+    /// Replaces an asymmetric profile set by `ease_in_out`
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self.ease_out = None;
+        self
+    }
+
+    /// Accelerate following `ease_in` and decelerate following `ease_out` on
+    /// [`MouseInterpolation::Linear`] movements, blending smoothly between the two
+    ///
+    /// `Easing::Sine` then `Easing::EaseIn` starts gently and brakes sharply on
+    /// arrival
+    pub fn ease_in_out(mut self, ease_in: Easing, ease_out: Easing) -> Self {
+        self.easing = ease_in;
+        self.ease_out = Some(ease_out);
+        self
+    }
+
+    /// Wait for an animated target to stop moving before aiming at it, reading
+    /// its rect every `interval_ms` until two reads are within `tolerance_px`
+    ///
+    /// Aims at the latest position once `timeout_ms` elapsed without settling
+    pub fn wait_for_stable_rect(
+        mut self,
+        interval_ms: u64,
+        tolerance_px: f64,
+        timeout_ms: u64,
+    ) -> Self {
+        self.settle = Some(Settle {
+            interval_ms,
+            tolerance_px,
+            timeout_ms,
+        });
+        self
+    }
+
+    /// Seed for the random number generator, the same seed, start and target
+    /// always produce the same path
+    ///
+    /// Seeds drive a ChaCha8 generator, whose output doesn't change across
+    /// platforms nor `rand` releases, so a seed from a bug report or snapshot
+    /// test keeps reproducing the same path
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Own random number generator for every random choice of the movement,
+    /// `factory` being called once per movement, taking precedence over the seed
+    ///
+    /// A factory returning the same deterministic generator every time
+    /// reproduces paths like a seed does. Can't be serialized.
+    pub fn rng_factory<R: RngCore + Send + 'static>(
+        mut self,
+        factory: impl Fn() -> R + Send + Sync + 'static,
+    ) -> Self {
+        self.rng_factory = Some(RngFactory(Arc::new(move || Box::new(factory()))));
+        self
+    }
+
+    /// Estimated time in milliseconds the driver takes to perform a single move,
+    /// the duration is divided by it to get the number of steps in the path
+    ///
+    /// Defaults to 7ms, slower setups such as remote grids should raise it to
+    /// get fewer, coarser steps for the same duration
+    pub fn action_time_ms(mut self, action_time_ms: u64) -> Self {
+        self.action_time_ms = action_time_ms;
+        self
+    }
+
+    /// Number of moves per second, the duration is split into
+    /// `duration_ms * fps / 1000` steps instead of being divided by
+    /// `action_time_ms`, so the path resolution no longer depends on the driver
+    ///
+    /// Pair it with precise timing to also pace the moves at this rate
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.fps = Some(fps);
+        self
+    }
+
+    /// Send every move on its own and sleep between them so the movement takes
+    /// close to the requested duration, regardless of how fast the driver is
+    ///
+    /// Trades the single batched request for one request per step
+    pub fn precise_timing(mut self, precise_timing: bool) -> Self {
+        self.precise_timing = precise_timing;
+        self
+    }
+
+    /// Keep every point inside the viewport, reading its size once per
+    /// movement, so jitter and overshoot can't move out of bounds
+    ///
+    /// Enabled by default
+    pub fn clamp_to_viewport(mut self, clamp_to_viewport: bool) -> Self {
+        self.clamp_to_viewport = clamp_to_viewport;
+        self
+    }
+
+    /// Scroll the target into view before reading its position, so targets
+    /// below the fold can be reached
+    ///
+    /// Enabled by default, with several targets only the first one is scrolled to
+    pub fn scroll_into_view(mut self, scroll_into_view: bool) -> Self {
+        self.scroll_into_view = scroll_into_view;
+        self
+    }
+
+    /// Send the moves in device pixels, scaled by `window.devicePixelRatio`,
+    /// for drivers expecting them on high-DPI displays
+    ///
+    /// Disabled by default, the returned points stay in CSS pixels either way
+    pub fn device_pixel_scaling(mut self, device_pixel_scaling: bool) -> Self {
+        self.device_pixel_scaling = device_pixel_scaling;
+        self
+    }
+
+    /// Check that the landing point hits the target element or one of its
+    /// descendants before moving, failing with [`MouseActionError::Obscured`]
+    /// when an overlay such as a cookie banner covers it
+    ///
+    /// Applies to every landing point on an element, the source and target of
+    /// a drag and each target of a path included. Chains built with
+    /// `build_mouse_action_chain` are checked as they are built.
+    ///
+    /// Disabled by default, costs a script round-trip per landing point
+    pub fn verify_clickable(mut self, verify_clickable: bool) -> Self {
+        self.verify_clickable = verify_clickable;
+        self
+    }
+
+    /// Send the moves through the Chrome DevTools Protocol, one command per
+    /// move paced like precise timing, instead of WebDriver actions
+    ///
+    /// Buttons are pressed through CDP as well, where its pointer is. Browsers
+    /// without CDP and pointer types other than the mouse fall back to
+    /// WebDriver actions.
+    #[cfg(feature = "cdp")]
+    pub fn cdp_moves(mut self, cdp_moves: bool) -> Self {
+        self.cdp_moves = cdp_moves;
+        self
+    }
+
+    /// Move past the target by this fraction of the total distance, then
+    /// correct back onto the landing point over the last part of the path
+    pub fn overshoot(mut self, overshoot: f64) -> Self {
+        self.overshoot = Some(overshoot);
+        self
+    }
+
+    /// Skip the movement when the landing point is closer than this many
+    /// pixels, performing the actions where the mouse already is
+    ///
+    /// Disabled by default, nothing is moved nor returned for a skipped movement
+    pub fn min_move_distance(mut self, min_move_distance: f64) -> Self {
+        self.min_move_distance = min_move_distance;
+        self
+    }
+
+    pub fn landing_distribution(mut self, landing_distribution: LandingDistribution) -> Self {
+        self.landing_distribution = landing_distribution;
+        self
+    }
+
+    /// How far from the middle of the element the final point can land, as a
+    /// fraction of its half width and half height
+    ///
+    /// Defaults to 0.5, the middle half of the element. 0.0 always lands on the
+    /// middle and 1.0 anywhere on the element.
+    pub fn landing_spread(mut self, landing_spread: f64) -> Self {
+        self.landing_spread = landing_spread;
+        self
+    }
+
+    /// Same as [`build`](Self::build), failing with every setting it would
+    /// otherwise clamp, reorder or ignore instead of silently fixing them up
+    pub fn try_build(self) -> Result<MouseAction, Vec<ConfigError>> {
+        let errors = self.config_errors();
+        if errors.is_empty() {
+            Ok(self.build())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn config_errors(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let within = |value: f64, min: f64, max: f64| (min..=max).contains(&value);
+
+        if self.action_time_ms == 0 {
+            errors.push(ConfigError::new("action_time_ms", "must be above 0"));
+        }
+        if self.fps == Some(0) {
+            errors.push(ConfigError::new("fps", "must be above 0"));
+        }
+        if self.max_steps == 0 {
+            errors.push(ConfigError::new("max_steps", "must be above 0"));
+        }
+        match self.steps {
+            Some(0) => errors.push(ConfigError::new("steps", "must be above 0")),
+            Some(steps) if steps > self.max_steps => errors.push(ConfigError::new(
+                "steps",
+                format!("{steps} is above max_steps {}", self.max_steps),
+            )),
+            _ => {}
+        }
+        match self.pacing {
+            Pacing::Speed(_) | Pacing::Fitts { .. } if self.steps.is_some() => errors.push(
+                ConfigError::new("steps", "conflicts with speed or Fitts's law pacing"),
+            ),
+            Pacing::Speed(speed) if !(speed.is_finite() && speed > 0.00) => errors.push(
+                ConfigError::new("speed_px_per_s", format!("must be above 0, got {speed}")),
+            ),
+            Pacing::Fitts { a, b } if !(a.is_finite() && b.is_finite()) => {
+                errors.push(ConfigError::new("fitts_law", "coefficients must be finite"))
+            }
+            _ => {}
+        }
+        if self.jitter_x < 0 {
+            errors.push(ConfigError::new("jitter_x", "must not be negative"));
+        }
+        if self.jitter_y < 0 {
+            errors.push(ConfigError::new("jitter_y", "must not be negative"));
+        }
+        if !within(self.jitter_probability, 0.00, 1.00) {
+            errors.push(ConfigError::new(
+                "jitter_probability",
+                format!("must be within 0.0..=1.0, got {}", self.jitter_probability),
+            ));
+        }
+        if !within(self.jitter_decay, 0.00, 1.00) {
+            errors.push(ConfigError::new(
+                "jitter_decay",
+                format!("must be within 0.0..=1.0, got {}", self.jitter_decay),
+            ));
+        }
+        if !within(self.landing_spread, 0.00, 1.00) {
+            errors.push(ConfigError::new(
+                "landing_spread",
+                format!("must be within 0.0..=1.0, got {}", self.landing_spread),
+            ));
+        }
+        if let Some(pixels) = self.max_pixels_per_step {
+            if !(pixels.is_finite() && pixels > 0.00) {
+                errors.push(ConfigError::new(
+                    "max_pixels_per_step",
+                    format!("must be above 0, got {pixels}"),
+                ));
+            }
+        }
+        if let Some(pixels) = self.max_speed_px_per_step {
+            if !(pixels.is_finite() && pixels >= 1.00) {
+                errors.push(ConfigError::new(
+                    "max_speed_px_per_step",
+                    format!("must be at least 1, got {pixels}"),
+                ));
+            }
+        }
+        if let Some(pixels) = self.merge_threshold_px {
+            if !(pixels.is_finite() && pixels > 0.00) {
+                errors.push(ConfigError::new(
+                    "merge_threshold_px",
+                    format!("must be above 0, got {pixels}"),
+                ));
+            }
+        }
+        if let Some(overshoot) = self.overshoot {
+            if !(overshoot.is_finite() && overshoot >= 0.00) {
+                errors.push(ConfigError::new(
+                    "overshoot",
+                    format!("must not be negative, got {overshoot}"),
+                ));
+            }
+        }
+        if self.min_move_distance.is_nan() {
+            errors.push(ConfigError::new("min_move_distance", "must be a number"));
+        }
+        if let Some((amplitude, frequency)) = self.sine_wobble {
+            if !(amplitude.is_finite() && frequency.is_finite()) {
+                errors.push(ConfigError::new("sine_wobble", "must be finite"));
+            }
+        }
+        if let Some((count, max_ms)) = self.mid_path_pauses {
+            if count == 0 || max_ms == 0 {
+                errors.push(ConfigError::new(
+                    "mid_path_pauses",
+                    "count and duration must be above 0",
+                ));
+            }
+        }
+        if !(self.spline_control_range.is_finite() && self.spline_control_range >= 0.00) {
+            errors.push(ConfigError::new(
+                "spline_control_range",
+                format!("must not be negative, got {}", self.spline_control_range),
+            ));
+        }
+        if !self.curve_bias.is_finite() {
+            errors.push(ConfigError::new("curve_bias", "must be finite"));
+        }
+        match self.interpolation {
+            MouseInterpolation::CubicBezier { c1, c2 }
+                if ![c1.0, c1.1, c2.0, c2.1]
+                    .iter()
+                    .all(|value| value.is_finite()) =>
+            {
+                errors.push(ConfigError::new(
+                    "interpolation",
+                    "cubic Bézier control points must be finite",
+                ))
+            }
+            MouseInterpolation::Wind { gravity, wind }
+                if !(gravity.is_finite() && wind.is_finite()) =>
+            {
+                errors.push(ConfigError::new(
+                    "interpolation",
+                    "wind gravity and strength must be finite",
+                ))
+            }
+            _ => {}
+        }
+        for (setting, easing) in [
+            ("easing", Some(&self.easing)),
+            ("ease_out", self.ease_out.as_ref()),
+        ] {
+            if let Some(Easing::Plateau(strength)) = easing {
+                if !within(*strength, 0.00, 1.00) {
+                    errors.push(ConfigError::new(
+                        setting,
+                        format!("plateau strength must be within 0.0..=1.0, got {strength}"),
+                    ));
+                }
+            }
+        }
+        if let Anchor::Offset { x, y } = self.anchor {
+            if !(within(x, 0.00, 1.00) && within(y, 0.00, 1.00)) {
+                errors.push(ConfigError::new(
+                    "anchor",
+                    format!("offset must be within 0.0..=1.0, got ({x}, {y})"),
+                ));
+            }
+        }
+        for (setting, range) in [
+            ("dwell_ms", self.dwell_ms),
+            ("reaction_delay_ms", self.reaction_delay_ms),
+            ("click_hold_ms", self.click_hold_ms),
+        ] {
+            if let Some((min_ms, max_ms)) = range {
+                if min_ms > max_ms {
+                    errors.push(ConfigError::new(
+                        setting,
+                        format!("minimum {min_ms} is above maximum {max_ms}"),
+                    ));
+                }
+            }
+        }
+        if let PointerType::Pen {
+            pressure,
+            tangential_pressure,
+        } = self.pointer_type
+        {
+            if let Some((ends, middle)) = pressure {
+                if !(within(ends, 0.00, 1.00) && within(middle, 0.00, 1.00)) {
+                    errors.push(ConfigError::new(
+                        "pointer_type",
+                        "pen pressure must be within 0.0..=1.0",
+                    ));
+                }
+            }
+            if !within(tangential_pressure, -1.00, 1.00) {
+                errors.push(ConfigError::new(
+                    "pointer_type",
+                    "pen tangential pressure must be within -1.0..=1.0",
+                ));
+            }
+        }
+
+        errors
+    }
+
+    pub fn build(self) -> MouseAction {
+        let divider = self.action_time_ms.max(1);
+        let fps = self.fps.filter(|fps| *fps > 0);
+        let max_steps = self.max_steps.max(1);
+        let steps = match (self.steps, fps) {
+            (Some(steps), _) => steps as u64,
+            (None, Some(fps)) => self.duration_ms.saturating_mul(fps as u64) / 1000,
+            (None, None) => self.duration_ms / divider,
+        }
+        .clamp(1, max_steps as u64) as usize;
+
+        MouseAction {
+            interpolation: self.interpolation,
+            start_action: self.start_action,
+            end_action: self.end_action,
+            duration_ms: self.duration_ms,
+            steps,
+            fixed_steps: self.steps.is_some(),
+            max_steps,
+            max_pixels_per_step: self
+                .max_pixels_per_step
+                .filter(|pixels| pixels.is_finite() && *pixels > 0.00),
+            // Points are whole pixels, a finer limit could never be met
+            max_speed_px_per_step: self
+                .max_speed_px_per_step
+                .filter(|pixels| pixels.is_finite() && *pixels > 0.00)
+                .map(|pixels| pixels.max(1.00)),
+            merge_threshold_px: self
+                .merge_threshold_px
+                .filter(|pixels| pixels.is_finite() && *pixels > 0.00),
+            pacing: match self.pacing {
+                _ if self.steps.is_some() => Pacing::Duration,
+                Pacing::Speed(speed) if speed <= 0.00 => Pacing::Duration,
+                pacing => pacing,
+            },
+            // Negative amounts disable jitter, normalized so no range is built from them
+            jitter_x: self.jitter_x.max(0),
+            jitter_y: self.jitter_y.max(0),
+            seed: self.seed,
+            rng_factory: self.rng_factory,
+            action_time_ms: divider,
+            fps,
+            precise_timing: self.precise_timing,
+            clamp_to_viewport: self.clamp_to_viewport,
+            scroll_into_view: self.scroll_into_view,
+            device_pixel_scaling: self.device_pixel_scaling,
+            cdp_moves: self.cdp_moves,
+            verify_clickable: self.verify_clickable,
+            overshoot: self.overshoot.filter(|overshoot| overshoot.is_finite()),
+            min_move_distance: self.min_move_distance,
+            landing_distribution: self.landing_distribution,
+            landing_spread: self.landing_spread.clamp(0.00, 1.00),
+            jitter_decay: self.jitter_decay,
+            jitter_probability: if self.jitter_probability.is_nan() {
+                0.00
+            } else {
+                self.jitter_probability.clamp(0.00, 1.00)
+            },
+            jitter_distribution: self.jitter_distribution,
+            pointer_type: self.pointer_type,
+            sine_wobble: self
+                .sine_wobble
+                .filter(|(amplitude, frequency)| amplitude.is_finite() && frequency.is_finite()),
+            mid_path_pauses: self
+                .mid_path_pauses
+                .filter(|(count, max_ms)| *count > 0 && *max_ms > 0),
+            dwell_ms: ordered_range(self.dwell_ms),
+            reaction_delay_ms: ordered_range(self.reaction_delay_ms),
+            modifiers: self.modifiers,
+            click_hold_ms: ordered_range(self.click_hold_ms),
+            on_step: self.on_step,
+            default_start: self.default_start,
+            probe_position: self.probe_position,
+            retry: self.retry,
+            refind_stale: self.refind_stale,
+            return_to_origin: self.return_to_origin,
+            anchor: self.anchor,
+            settle: self.settle,
+            easing: self.easing,
+            ease_out: self.ease_out,
+            spline_control_range: if self.spline_control_range.is_finite() {
+                self.spline_control_range.max(0.00)
+            } else {
+                1.00
+            },
+            curve_bias: if self.curve_bias.is_finite() {
+                self.curve_bias
+            } else {
+                0.00
+            },
+        }
+    }
+}
+
+impl From<MouseActionBuilder> for MouseAction {
+    fn from(builder: MouseActionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<MouseAction> for MouseActionBuilder {
+    fn from(action: MouseAction) -> Self {
+        MouseActionBuilder {
+            interpolation: action.interpolation,
+            start_action: action.start_action,
+            end_action: action.end_action,
+            duration_ms: action.duration_ms,
+            steps: action.fixed_steps.then_some(action.steps),
+            max_steps: action.max_steps,
+            max_pixels_per_step: action.max_pixels_per_step,
+            max_speed_px_per_step: action.max_speed_px_per_step,
+            merge_threshold_px: action.merge_threshold_px,
+            pacing: action.pacing,
+            jitter_x: action.jitter_x,
+            jitter_y: action.jitter_y,
+            seed: action.seed,
+            rng_factory: action.rng_factory,
+            action_time_ms: action.action_time_ms,
+            fps: action.fps,
+            precise_timing: action.precise_timing,
+            clamp_to_viewport: action.clamp_to_viewport,
+            scroll_into_view: action.scroll_into_view,
+            device_pixel_scaling: action.device_pixel_scaling,
+            cdp_moves: action.cdp_moves,
+            verify_clickable: action.verify_clickable,
+            overshoot: action.overshoot,
+            min_move_distance: action.min_move_distance,
+            landing_distribution: action.landing_distribution,
+            landing_spread: action.landing_spread,
+            jitter_decay: action.jitter_decay,
+            jitter_probability: action.jitter_probability,
+            jitter_distribution: action.jitter_distribution,
+            pointer_type: action.pointer_type,
+            sine_wobble: action.sine_wobble,
+            mid_path_pauses: action.mid_path_pauses,
+            dwell_ms: action.dwell_ms,
+            reaction_delay_ms: action.reaction_delay_ms,
+            modifiers: action.modifiers,
+            click_hold_ms: action.click_hold_ms,
+            on_step: action.on_step,
+            default_start: action.default_start,
+            probe_position: action.probe_position,
+            retry: action.retry,
+            refind_stale: action.refind_stale,
+            return_to_origin: action.return_to_origin,
+            anchor: action.anchor,
+            curve_bias: action.curve_bias,
+            spline_control_range: action.spline_control_range,
+            easing: action.easing,
+            ease_out: action.ease_out,
+            settle: action.settle,
+        }
+    }
+}
+
+#[async_trait]
+pub trait MouseActionExt {
+    async fn mouse_action(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    #[cfg(feature = "runtime-tokio")]
+    async fn mouse_action_cancellable(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+        cancel: &CancellationToken,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_action_by(
+        &self,
+        action: MouseAction,
+        by: By,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_action_in_frame(
+        &self,
+        action: MouseAction,
+        frame: &WebElement,
+        target_element: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_action_to_point(
+        &self,
+        action: MouseAction,
+        x: i64,
+        y: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_move_by(
+        &self,
+        action: MouseAction,
+        dx: i64,
+        dy: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn drag_and_drop(
+        &self,
+        action: MouseAction,
+        source: &WebElement,
+        target: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_action_path(
+        &self,
+        action: MouseAction,
+        targets: &[&WebElement],
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn drag_along_path(
+        &self,
+        action: MouseAction,
+        points: &[(i64, i64)],
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn oscillate(
+        &self,
+        action: MouseAction,
+        element: &WebElement,
+        cycles: usize,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn scroll(
+        &self,
+        element: &WebElement,
+        delta_x: i64,
+        delta_y: i64,
+    ) -> MouseActionResult<()>;
+
+    async fn scroll_then_mouse_action(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+        delta_x: i64,
+        delta_y: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn click(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn double_click(&self, target_element: &WebElement)
+        -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn right_click(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn hover(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    async fn hover_for(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+        dwell_ms: u64,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    async fn idle_tremor(
+        &self,
+        around: &WebElement,
+        duration_ms: u64,
+        amplitude: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn click_here(&self, action: MouseButtonAction) -> MouseActionResult<()>;
+
+    async fn current_mouse_position(&self) -> MouseActionResult<(i64, i64)>;
+
+    async fn build_mouse_action_chain(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+    ) -> MouseActionResult<ActionChain>;
+
+    fn clear_mouse_position_cache(&self);
+
+    fn forget_session(&self);
+
+    async fn reset_mouse_tracking(&self) -> MouseActionResult<()>;
+
+    async fn set_mouse_position(&self, x: i64, y: i64) -> MouseActionResult<()>;
+
+    async fn persist_mouse_position(&self, persist: bool) -> MouseActionResult<()>;
+}
+
+#[async_trait]
+impl MouseActionExt for WebDriver {
+    /// Simulate mouse movement across a path over a duration, returning every
+    /// point that was sent to the browser
+    ///
+    /// Note: There is no guarantee the duration is exact, but should be close
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn mouse_action(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        move_to_element(self, action, target_element, None).await
+    }
+
+    /// Same as `mouse_action`, stopping early with [`MouseActionError::Cancelled`]
+    /// once `cancel` is cancelled and leaving the mouse where it stopped, with
+    /// the `runtime-tokio` feature
+    ///
+    /// Always paced like precise timing so it can stop between moves, a
+    /// button held by the start action stays pressed when cancelled
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    #[cfg(feature = "runtime-tokio")]
+    async fn mouse_action_cancellable(
+        &self,
+        mut action: MouseAction,
+        target_element: &WebElement,
+        cancel: &CancellationToken,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        action.precise_timing = true;
+        move_to_element(self, action, target_element, Some(cancel)).await
+    }
+
+    /// Find the element matching the selector and move to it like `mouse_action`
+    ///
+    /// Fails with [`MouseActionError::ElementNotFound`] when no element matches,
+    /// other lookup errors are retried like the movement itself. The element
+    /// is found again once when it went stale and the action allows it
+    async fn mouse_action_by(
+        &self,
+        action: MouseAction,
+        by: By,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let target_element = find_target(self, &action, by.clone()).await?;
+
+        match self.mouse_action(action.clone(), &target_element).await {
+            Err(MouseActionError::StaleElement(_)) if action.refind_stale => {
+                let target_element = find_target(self, &action, by).await?;
+                self.mouse_action(action, &target_element).await
+            }
+            result => result,
+        }
+    }
+
+    /// Simulate mouse movement to an element inside the iframe `frame`, its
+    /// rect being translated by the offset of the frame in the viewport
+    ///
+    /// Called from the document holding `frame`, the element having been found
+    /// while within the frame. The session is switched into the frame to read
+    /// the rect of the element and back to its parent before moving, a single
+    /// level of frames is handled per call
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn mouse_action_in_frame(
+        &self,
+        action: MouseAction,
+        frame: &WebElement,
+        target_element: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let start = start_position(self, &action).await?;
+
+        if action.scroll_into_view {
+            frame.scroll_into_view().await.map_err(element_error)?;
+        }
+        let (offset_x, offset_y) = frame_offset(self, frame).await?;
+
+        frame.clone().enter_frame().await.map_err(element_error)?;
+        let rect = target_rect(&action, target_element).await;
+        self.enter_parent_frame().await?;
+        let mut target_rect = rect?;
+        target_rect.x += offset_x;
+        target_rect.y += offset_y;
+        debug!(?target_rect, "target rect in frame");
+
+        let end = landing_point(&action, &target_rect, &mut rng);
+        if action.verify_clickable {
+            // Hit tested within the frame, in the coordinates of its content
+            frame.clone().enter_frame().await.map_err(element_error)?;
+            let in_frame = (end.0 - offset_x as i64, end.1 - offset_y as i64);
+            let verified = verify_clickable(self, &action, target_element, in_frame).await;
+            self.enter_parent_frame().await?;
+            verified?;
+        }
+
+        let positions = create_path(&action, start, end, Some(&target_rect), &mut rng)?;
+        perform_path(
+            self,
+            &action,
+            vec![Stroke::new(action.start_action.clone(), positions)],
+            &action.end_action,
+            start,
+            None,
+            &mut rng,
+        )
+        .await
+    }
+
+    /// Simulate mouse movement across a path to a point in the viewport
+    ///
+    /// Note: There is no guarantee the duration is exact, but should be close
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, action))
+    )]
+    async fn mouse_action_to_point(
+        &self,
+        action: MouseAction,
+        x: i64,
+        y: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let start = start_position(self, &action).await?;
+        perform_from(self, &action, start, (x, y), &mut rng).await
+    }
+
+    /// Same as `mouse_action_to_point` to a point offset from the current
+    /// mouse position, negative offsets moving up and left
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, action))
+    )]
+    async fn mouse_move_by(
+        &self,
+        action: MouseAction,
+        dx: i64,
+        dy: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let start = start_position(self, &action).await?;
+        let target = (start.0 + dx, start.1 + dy);
+        perform_from(self, &action, start, target, &mut rng).await
+    }
+
+    /// Move to the source element, press the left button, drag along a single
+    /// continuous path to the target element and release there
+    ///
+    /// The start and end actions of `action` are ignored, the button is held for the whole drag
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn drag_and_drop(
+        &self,
+        action: MouseAction,
+        source: &WebElement,
+        target: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let start = start_position(self, &action).await?;
+
+        // Scrolling to the target could scroll the source back out of view
+        let source_rect = target_rect(&action, source).await?;
+        let target_rect = settled_rect(&action, target).await?;
+        let grab = landing_point(&action, &source_rect, &mut rng);
+        let drop = landing_point(&action, &target_rect, &mut rng);
+        verify_clickable(self, &action, source, grab).await?;
+        verify_clickable(self, &action, target, drop).await?;
+
+        let approach = create_path(&action, start, grab, Some(&source_rect), &mut rng)?;
+        let drag = create_path(&action, grab, drop, Some(&target_rect), &mut rng)?;
+        perform_path(
+            self,
+            &action,
+            vec![
+                Stroke::new(MouseButtonAction::None, approach),
+                Stroke::new(MouseButtonAction::LeftHold, drag),
+            ],
+            &MouseButtonAction::LeftRelease,
+            start,
+            None,
+            &mut rng,
+        )
+        .await
+    }
+
+    /// Move through every target in order along one continuous path, the
+    /// start action is performed before the first leg and the end action at
+    /// the last target, so a held button stays pressed across every target
+    ///
+    /// Every leg between two targets uses the duration of `action`
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn mouse_action_path(
+        &self,
+        action: MouseAction,
+        targets: &[&WebElement],
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let origin = start_position(self, &action).await?;
+
+        let mut waypoints = vec![origin];
+        let mut target_rects = Vec::new();
+        for (index, target) in targets.iter().enumerate() {
+            // Only the first target is scrolled to, scrolling to the others
+            // would move the ones already planned
+            let target_rect = if index == 0 {
+                target_rect(&action, target).await?
+            } else {
+                settled_rect(&action, target).await?
+            };
+            let landing = landing_point(&action, &target_rect, &mut rng);
+            verify_clickable(self, &action, target, landing).await?;
+            waypoints.push(landing);
+            target_rects.push(target_rect);
+        }
+        let targets = target_rects.iter().map(Some).collect::<Vec<_>>();
+        let positions = waypoint_path(&action, &waypoints, &targets, &mut rng)?;
+
+        perform_path(
+            self,
+            &action,
+            vec![Stroke::new(action.start_action.clone(), positions)],
+            &action.end_action,
+            origin,
+            None,
+            &mut rng,
+        )
+        .await
+    }
+
+    /// Move to the first viewport point, press the left button, drag through
+    /// every following point and release on the last one
+    ///
+    /// Every leg between two points is interpolated with the duration of
+    /// `action`, the start and end actions are replaced by the drag. Nothing
+    /// happens without points
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn drag_along_path(
+        &self,
+        action: MouseAction,
+        points: &[(i64, i64)],
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let Some(first) = points.first() else {
+            return Ok(Vec::new());
+        };
+
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let start = start_position(self, &action).await?;
+
+        let approach = create_path(&action, start, *first, None, &mut rng)?;
+        let targets = vec![None; points.len() - 1];
+        let drag = waypoint_path(&action, points, &targets, &mut rng)?;
+        perform_path(
+            self,
+            &action,
+            vec![
+                Stroke::new(MouseButtonAction::None, approach),
+                Stroke::new(MouseButtonAction::LeftHold, drag),
+            ],
+            &MouseButtonAction::LeftRelease,
+            start,
+            None,
+            &mut rng,
+        )
+        .await
+    }
+
+    /// Move onto the element and back to where the mouse started, `cycles`
+    /// times, each leg starting where the previous one ended
+    ///
+    /// The start action is performed before the first leg and the end action
+    /// on every arrival at the element, a new landing point is picked each time
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn oscillate(
+        &self,
+        action: MouseAction,
+        element: &WebElement,
+        cycles: usize,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let mut rng = action.rng();
+        sleep(action.reaction_delay(&mut rng)).await;
+        let origin = start_position(self, &action).await?;
+
+        let target_rect = target_rect(&action, element).await?;
+        let mut strokes = Vec::new();
+        for cycle in 0..cycles {
+            let end = landing_point(&action, &target_rect, &mut rng);
+            verify_clickable(self, &action, element, end).await?;
+            let start_action = if cycle == 0 {
+                action.start_action.clone()
+            } else {
+                MouseButtonAction::None
+            };
+            strokes.push(Stroke::new(
+                start_action,
+                create_path(&action, origin, end, Some(&target_rect), &mut rng)?,
+            ));
+            // The end action happens on the element, before heading back
+            strokes.push(Stroke::new(
+                action.end_action.clone(),
+                create_path(&action, end, origin, None, &mut rng)?,
+            ));
+        }
+
+        perform_path(
+            self,
+            &action,
+            strokes,
+            &MouseButtonAction::None,
+            origin,
+            None,
+            &mut rng,
+        )
+        .await
+    }
+
+    /// Scroll the wheel over the middle of the element, negative deltas
+    /// scroll up and left
+    async fn scroll(
+        &self,
+        element: &WebElement,
+        delta_x: i64,
+        delta_y: i64,
+    ) -> MouseActionResult<()> {
+        pointer::scroll(self, element, delta_x, delta_y).await?;
+        Ok(())
+    }
+
+    /// Scroll the wheel over the middle of the element, then move to the
+    /// element at its scrolled position
+    async fn scroll_then_mouse_action(
+        &self,
+        mut action: MouseAction,
+        target_element: &WebElement,
+        delta_x: i64,
+        delta_y: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        self.scroll(target_element, delta_x, delta_y).await?;
+        // Keep the scroll position reached by the wheel
+        action.scroll_into_view = false;
+        self.mouse_action(action, target_element).await
+    }
+
+    /// Move to the element with the default movement and left click it
+    async fn click(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>> {
+        self.mouse_action(quick_action(MouseButtonAction::LeftClick), target_element)
+            .await
+    }
+
+    /// Move to the element with the default movement and double click it
+    async fn double_click(
+        &self,
+        target_element: &WebElement,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        self.mouse_action(quick_action(MouseButtonAction::DoubleClick), target_element)
+            .await
+    }
+
+    /// Move to the element with the default movement and right click it
+    async fn right_click(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>> {
+        self.mouse_action(quick_action(MouseButtonAction::RightClick), target_element)
+            .await
+    }
+
+    /// Move onto the element with the default movement without pressing any
+    /// button
+    async fn hover(&self, target_element: &WebElement) -> MouseActionResult<Vec<(i64, i64)>> {
+        self.mouse_action(quick_action(MouseButtonAction::None), target_element)
+            .await
+    }
+
+    /// Move onto the element like `mouse_action` without pressing any button,
+    /// then stay still on it for `dwell_ms` so hover menus and tooltips open
+    ///
+    /// Replaces the end action and dwell of `action`
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    async fn hover_for(
+        &self,
+        mut action: MouseAction,
+        target_element: &WebElement,
+        dwell_ms: u64,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        action.end_action = MouseButtonAction::None;
+        action.dwell_ms = Some((dwell_ms, dwell_ms));
+        self.mouse_action(action, target_element).await
+    }
+
+    /// Keep the mouse trembling around the middle of the element for the
+    /// duration, with tiny random moves of at most `amplitude` pixels like a
+    /// resting hand, returning every point moved to
+    ///
+    /// The first move goes straight to the element, move onto it first with a
+    /// regular movement such as `hover`
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    async fn idle_tremor(
+        &self,
+        around: &WebElement,
+        duration_ms: u64,
+        amplitude: i64,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        let rect = element_rect(around).await?;
+        let center = (
+            (rect.x + rect.width / 2.00) as i64,
+            (rect.y + rect.height / 2.00) as i64,
+        );
+        let amplitude = amplitude.max(0);
+
+        let mut rng = ChaCha8Rng::from_entropy();
+        let end = Instant::now() + Duration::from_millis(duration_ms);
+        let mut positions = Vec::new();
+        while Instant::now() < end {
+            let point = (
+                (center.0 + rng.gen_range(-amplitude..=amplitude)).max(0),
+                (center.1 + rng.gen_range(-amplitude..=amplitude)).max(0),
+            );
+            if let Err(err) = self
+                .action_chain_with_delay(None, Some(0))
+                .move_to(point.0, point.1)
+                .perform()
+                .await
+            {
+                tracker::clear(self);
+                return Err(err.into());
+            }
+            tracker::store(self, point);
+            positions.push(point);
+
+            let pause = Duration::from_millis(rng.gen_range(TREMOR_INTERVAL_MS));
+            sleep_until((Instant::now() + pause).min(end)).await;
+        }
+
+        Ok(positions)
+    }
+
+    /// Perform the button action where the mouse already is, without moving
+    /// it or looking up any element
+    ///
+    /// Meant for after `mouse_move_by` or `idle_tremor`, a held button stays
+    /// pressed until a later action releases it
+    async fn click_here(&self, action: MouseButtonAction) -> MouseActionResult<()> {
+        let batch = if action.requires_pointer_actions() {
+            InputBatch::Pointer(PointerActions::new(PointerType::Mouse))
+        } else {
+            InputBatch::Chain(self.action_chain_with_delay(None, Some(0)))
+        };
+        batch.button(&action, None)?.perform(self).await?;
+        Ok(())
+    }
+
+    /// Where the mouse currently is in the viewport, the same position the
+    /// next movement starts from
+    ///
+    /// Uses the cached position when known, otherwise reads it from the page
+    /// and may move the mouse by a pixel to find it, or to the middle of the
+    /// viewport when it still can't be found
+    async fn current_mouse_position(&self) -> MouseActionResult<(i64, i64)> {
+        mouse_position(self, None, true).await
+    }
+
+    /// Build the movement of `mouse_action` into an `ActionChain` without
+    /// performing it, so further actions can be appended and performed in one go
+    ///
+    /// The chain has no pauses, so the delays, dwell and click hold of `action`
+    /// are not applied. Buttons only sent as raw pointer actions are rejected.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    async fn build_mouse_action_chain(
+        &self,
+        action: MouseAction,
+        target_element: &WebElement,
+    ) -> MouseActionResult<ActionChain> {
+        // Rejected before moving anything, the chain below would only fail halfway
+        for button in [&action.start_action, &action.end_action] {
+            if button.requires_pointer_actions() {
+                return Err(MouseActionError::ChainUnsupported(button.clone()));
+            }
+        }
+
+        let mut rng = action.rng();
+        let start = start_position(self, &action).await?;
+
+        let target_rect = target_rect(&action, target_element).await?;
+        let end = landing_point(&action, &target_rect, &mut rng);
+        // Checked as the chain is built, the caller performs it later
+        verify_clickable(self, &action, target_element, end).await?;
+
+        let positions = create_path(&action, start, end, Some(&target_rect), &mut rng)?;
+        let mut strokes = vec![Stroke::new(action.start_action.clone(), positions)];
+        clamp_to_viewport(self, &action, &mut strokes).await?;
+        let scale = pixel_scale(self, &action).await?;
+
+        // Performed by the caller, where the mouse ends up is unknown
+        tracker::clear(self);
+
+        let mut action_chain = self.action_chain_with_delay(None, Some(0));
+        for modifier in &action.modifiers {
+            action_chain = action_chain.key_down(*modifier);
+        }
+        for stroke in &strokes {
+            action_chain = stroke.action.action(action_chain)?;
+            for point in &stroke.positions {
+                let (x, y) = to_device(*point, scale);
+                action_chain = action_chain.move_to(x, y);
+            }
+        }
+        action_chain = action.end_action.action(action_chain)?;
+        for modifier in &action.modifiers {
+            action_chain = action_chain.key_up(*modifier);
+        }
+
+        Ok(action_chain)
+    }
+
+    /// Forget the mouse position cached after the last movement of this
+    /// session, the next movement reads it from the page again
+    ///
+    /// Call this after moving the mouse by other means than this crate
+    fn clear_mouse_position_cache(&self) {
+        tracker::clear(self);
+    }
+
+    /// Drop everything cached for this session, its mouse position, whether
+    /// it persists it and whether it supports CDP
+    ///
+    /// Call this before quitting a session, so suites creating many sessions
+    /// don't keep one entry per ended session around
+    fn forget_session(&self) {
+        tracker::forget(self);
+        #[cfg(feature = "cdp")]
+        cdp::forget(self);
+    }
+
+    /// Forget the mouse position in the cache and in the page, the next
+    /// movement finds it again
+    async fn reset_mouse_tracking(&self) -> MouseActionResult<()> {
+        tracker::reset(self).await
+    }
+
+    /// Tell the tracker where the mouse is without moving it, the next
+    /// movement starts from this point
+    async fn set_mouse_position(&self, x: i64, y: i64) -> MouseActionResult<()> {
+        tracker::seed(self, (x, y)).await
+    }
+
+    /// Mirror the tracked position of this session into `sessionStorage`, so
+    /// a page loaded on the same origin knows it without probing the mouse
+    ///
+    /// With the `cdp` feature on Chromium the position is restored as each new
+    /// document loads, elsewhere lazily by the next movement reading it
+    ///
+    /// Disabled by default as it writes to the storage of the page, disabling
+    /// it removes the stored position
+    async fn persist_mouse_position(&self, persist: bool) -> MouseActionResult<()> {
+        tracker::set_persistent(self, persist).await
+    }
+}
+
+#[async_trait]
+pub trait ElementMouseActionExt {
+    async fn mouse_action(&self, action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_hover(&self, action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_click(&self, action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>>;
+
+    async fn mouse_right_click(&self, action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>>;
+}
+
+#[async_trait]
+impl ElementMouseActionExt for WebElement {
+    /// Simulate mouse movement to this element using the driver of its
+    /// session, performing the start and end actions of `action`
+    async fn mouse_action(&self, action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>> {
+        let driver = WebDriver {
+            handle: self.handle.clone(),
+        };
+        driver.mouse_action(action, self).await
+    }
+
+    /// Move onto this element without pressing any button, named apart from
+    /// the inherent `WebElement` methods
+    ///
+    /// The end action of `action` is ignored
+    async fn mouse_hover(&self, mut action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>> {
+        action.end_action = MouseButtonAction::None;
+        ElementMouseActionExt::mouse_action(self, action).await
+    }
+
+    /// Move onto this element and left click it
+    ///
+    /// The end action of `action` is ignored
+    async fn mouse_click(&self, mut action: MouseAction) -> MouseActionResult<Vec<(i64, i64)>> {
+        action.end_action = MouseButtonAction::LeftClick;
+        ElementMouseActionExt::mouse_action(self, action).await
+    }
+
+    /// Move onto this element and right click it
+    ///
+    /// The end action of `action` is ignored
+    async fn mouse_right_click(
+        &self,
+        mut action: MouseAction,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        action.end_action = MouseButtonAction::RightClick;
+        ElementMouseActionExt::mouse_action(self, action).await
+    }
+}
+
+/// Movement used by the convenience methods, a linear 500ms path with a
+/// little jitter
+fn quick_action(end_action: MouseButtonAction) -> MouseAction {
+    MouseAction::builder()
+        .interpolation(MouseInterpolation::Linear)
+        .duration_ms(500)
+        .jitter_amount(2)
+        .end_action(end_action)
+        .build()
+}
+
+/// Random duration within an inclusive range of milliseconds, zero when unset
+fn random_delay(range_ms: Option<(u64, u64)>, rng: &mut impl Rng) -> Duration {
+    match range_ms {
+        Some((min_ms, max_ms)) => Duration::from_millis(rng.gen_range(min_ms..=max_ms)),
+        None => Duration::ZERO,
+    }
+}
+
+/// Range with its bounds in min, max order, deserialized ones may be swapped
+fn ordered_range(range: Option<(u64, u64)>) -> Option<(u64, u64)> {
+    range.map(|(a, b)| (a.min(b), a.max(b)))
+}
+
+/// Element matching the selector, looked up again after transient errors as
+/// the retry policy of `action` allows
+async fn find_target(
+    driver: &WebDriver,
+    action: &MouseAction,
+    by: By,
+) -> MouseActionResult<WebElement> {
+    retry(action.retry.as_ref(), || async {
+        driver.find(by.clone()).await.map_err(find_error)
+    })
+    .await
+}
+
+/// Where the movement starts, reading the mouse position like `action` asks to
+async fn start_position(driver: &WebDriver, action: &MouseAction) -> MouseActionResult<(i64, i64)> {
+    retry(action.retry.as_ref(), || {
+        mouse_position(driver, action.default_start, action.probe_position)
+    })
+    .await
+}
+
+/// Moves from the already resolved start to a point in the viewport,
+/// performing the start and end actions of `action`
+async fn perform_from(
+    driver: &WebDriver,
+    action: &MouseAction,
+    start: (i64, i64),
+    target: (i64, i64),
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    let positions = create_path(action, start, target, None, rng)?;
+    perform_path(
+        driver,
+        action,
+        vec![Stroke::new(action.start_action.clone(), positions)],
+        &action.end_action,
+        start,
+        None,
+        rng,
+    )
+    .await
+}
+
+/// Moves to a random point within the element, performing the start and end
+/// actions of `action`
+async fn move_to_element(
+    driver: &WebDriver,
+    action: MouseAction,
+    target_element: &WebElement,
+    cancel: Option<&CancellationToken>,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    let mut rng = action.rng();
+    sleep(action.reaction_delay(&mut rng)).await;
+    let start = start_position(driver, &action).await?;
+
+    let target_rect = target_rect(&action, target_element).await?;
+    let end = landing_point(&action, &target_rect, &mut rng);
+    verify_clickable(driver, &action, target_element, end).await?;
+
+    let positions = create_path(&action, start, end, Some(&target_rect), &mut rng)?;
+    perform_path(
+        driver,
+        &action,
+        vec![Stroke::new(action.start_action.clone(), positions)],
+        &action.end_action,
+        start,
+        cancel,
+        &mut rng,
+    )
+    .await
+}
+
+/// Fails when the action verifies the target is clickable and another element
+/// is drawn on top of it at the landing point
+async fn verify_clickable(
+    driver: &WebDriver,
+    action: &MouseAction,
+    element: &WebElement,
+    point: (i64, i64),
+) -> MouseActionResult<()> {
+    if !action.verify_clickable {
+        return Ok(());
+    }
+    match tracker::covering_element(driver, element, point).await? {
+        Some(covering) => Err(MouseActionError::Obscured(covering)),
+        None => Ok(()),
+    }
+}
+
+/// Range of the pause between two tremor moves
+#[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+const TREMOR_INTERVAL_MS: std::ops::RangeInclusive<u64> = 40..=160;
+
+/// Wait before reading the rect of an element without area again
+const ZERO_SIZE_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Position of the element, scrolled into view first when configured
+async fn target_rect(action: &MouseAction, element: &WebElement) -> MouseActionResult<ElementRect> {
+    if action.scroll_into_view {
+        element.scroll_into_view().await.map_err(element_error)?;
+    }
+    settled_rect(action, element).await
+}
+
+/// Position of the element, once it stopped moving when configured
+async fn settled_rect(
+    action: &MouseAction,
+    element: &WebElement,
+) -> MouseActionResult<ElementRect> {
+    if let Some(settle) = &action.settle {
+        wait_for_stable_rect(element, settle).await?;
+    }
+    element_rect(element).await
+}
+
+/// Polls the rect of the element until two reads in a row are within the
+/// tolerance, or gives up waiting once the timeout elapsed
+async fn wait_for_stable_rect(element: &WebElement, settle: &Settle) -> MouseActionResult<()> {
+    let deadline = Instant::now() + Duration::from_millis(settle.timeout_ms);
+    let mut previous = element.rect().await.map_err(element_error)?;
+    while Instant::now() < deadline {
+        sleep(Duration::from_millis(settle.interval_ms)).await;
+        let rect = element.rect().await.map_err(element_error)?;
+        let moved = [
+            rect.x - previous.x,
+            rect.y - previous.y,
+            rect.width - previous.width,
+            rect.height - previous.height,
+        ]
+        .iter()
+        .any(|delta| delta.abs() > settle.tolerance_px);
+        if !moved {
+            return Ok(());
+        }
+        previous = rect;
+    }
+    Ok(())
+}
+
+/// Position of the element, waiting once for an element without area to
+/// render before giving up on it
+async fn element_rect(element: &WebElement) -> MouseActionResult<ElementRect> {
+    let rect = viewport_rect(element).await?;
+    if has_area(&rect) {
+        debug!(?rect, "target rect");
+        return Ok(rect);
+    }
+
+    sleep(ZERO_SIZE_RETRY_DELAY).await;
+    let rect = viewport_rect(element).await?;
+    if has_area(&rect) {
+        debug!(?rect, "target rect");
+        Ok(rect)
+    } else {
+        Err(MouseActionError::ZeroSizeElement)
+    }
+}
+
+/// Whether the element covers any pixel a movement could land on
+fn has_area(rect: &ElementRect) -> bool {
+    rect.width > 0.00 && rect.height > 0.00
+}
+
+/// Rect of the element in viewport coordinates, like the tracked mouse
+/// position and the pointer moves
+///
+/// WebDriver reports the rect relative to the document, so the page scroll
+/// offset is taken off. Scrolled containers need no handling as their offset
+/// is already part of the position within the document
+async fn viewport_rect(element: &WebElement) -> MouseActionResult<ElementRect> {
+    let rect = element.rect().await.map_err(element_error)?;
+    let driver = WebDriver {
+        handle: element.handle.clone(),
+    };
+    Ok(document_to_viewport(rect, scroll_offset(&driver).await?))
+}
+
+/// Moves a rect relative to the document into the viewport scrolled by `scroll`
+fn document_to_viewport(mut rect: ElementRect, (scroll_x, scroll_y): (f64, f64)) -> ElementRect {
+    rect.x -= scroll_x;
+    rect.y -= scroll_y;
+    rect
+}
+
+/// Random point around the anchor of an element, picked following the
+/// configured landing distribution and spread, always within the element
+fn landing_point(action: &MouseAction, rect: &ElementRect, rng: &mut impl Rng) -> (i64, i64) {
+    let half_width = (rect.width / 2.00) as i64;
+    let half_height = (rect.height / 2.00) as i64;
+    // The last pixel inside the element is one short of its size
+    let last_x = (rect.width - 1.00).max(0.00);
+    let last_y = (rect.height - 1.00).max(0.00);
+    let (anchor_x, anchor_y) = action.anchor.fraction();
+    let target_pos_x = (rect.x + last_x * anchor_x) as i64;
+    let target_pos_y = (rect.y + last_y * anchor_y) as i64;
+
+    let spread_x = (half_width as f64 * action.landing_spread) as i64;
+    let spread_y = (half_height as f64 * action.landing_spread) as i64;
+    let (offset_x, offset_y) = match action.landing_distribution {
+        LandingDistribution::Uniform => (
+            rng.gen_range(-spread_x..=spread_x),
+            rng.gen_range(-spread_y..=spread_y),
+        ),
+        // Half the spread as the standard deviation keeps ~95% of the points within the spread
+        LandingDistribution::Gaussian => (
+            gaussian(spread_x as f64 / 2.00, rng) as i64,
+            gaussian(spread_y as f64 / 2.00, rng) as i64,
+        ),
+    };
+
+    let final_pos_x = (target_pos_x + offset_x).clamp(rect.x as i64, (rect.x + last_x) as i64);
+    let final_pos_y = (target_pos_y + offset_y).clamp(rect.y as i64, (rect.y + last_y) as i64);
+
+    (final_pos_x, final_pos_y)
+}
+
+/// Sample of a normal distribution centered on zero
+fn gaussian(std_dev: f64, rng: &mut impl Rng) -> f64 {
+    match Normal::new(0.00, std_dev) {
+        Ok(normal) => normal.sample(rng),
+        Err(_) => 0.00,
+    }
+}
+
+/// Interpolates a path between two points, overshooting the end when configured,
+/// and applies jitter
+fn create_path(
+    action: &MouseAction,
+    start: (i64, i64),
+    end: (i64, i64),
+    target: Option<&ElementRect>,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    let distance = ((end.0 - start.0) as f64).hypot((end.1 - start.1) as f64);
+    if distance < action.min_move_distance {
+        debug!(?start, ?end, distance, "movement skipped");
+        return Ok(Vec::new());
+    }
+
+    let steps = action.steps_between(start, end, target);
+    debug!(
+        ?start,
+        ?end,
+        steps,
+        interpolation = ?action.interpolation,
+        "creating path"
+    );
+    let mut positions = match action.overshoot.filter(|overshoot| *overshoot > 0.00) {
+        Some(overshoot) => {
+            let overshoot_point = (
+                end.0
+                    .saturating_add(((end.0 - start.0) as f64 * overshoot) as i64),
+                end.1
+                    .saturating_add(((end.1 - start.1) as f64 * overshoot) as i64),
+            );
+
+            // Roughly the last sixth of the path is spent correcting back onto the target
+            let correction_steps = (steps / 6).max(2).min(steps);
+            // Short paths are all correction, zero steps being an error to interpolate
+            let mut positions = match steps - correction_steps {
+                0 => Vec::new(),
+                approach_steps => interpolate(action, start, overshoot_point, approach_steps, rng)?,
+            };
+            // The first corrective point is the overshoot point itself
+            positions.extend(
+                create_linear_steps(
+                    overshoot_point.0,
+                    overshoot_point.1,
+                    end.0,
+                    end.1,
+                    correction_steps + 1,
+                )?
+                .into_iter()
+                .skip(1),
+            );
+            positions
+        }
+        None => interpolate(action, start, end, steps, rng)?,
+    };
+
+    if let Some((amplitude, frequency)) = action.sine_wobble {
+        wobble(
+            &mut positions,
+            start,
+            end,
+            amplitude,
+            frequency,
+            action.step_time_ms(),
+        );
+    }
+    if action.jitter_x > 0 || action.jitter_y > 0 {
+        jitter(action, &mut positions, rng);
+    }
+    if let Some(threshold) = action.merge_threshold_px {
+        merge_close_points(&mut positions, start, threshold);
+    }
+    if let Some(max_speed) = action.max_speed_px_per_step {
+        limit_speed(&mut positions, start, max_speed);
+    }
+
+    Ok(positions)
+}
+
+/// Path through every waypoint in order, one leg per pair of waypoints aimed
+/// at the matching target, or a single smooth curve through all of them with
+/// [`MouseInterpolation::CatmullRom`]
+///
+/// The curve is only jittered, the overshoot, wobble and bias of the legs
+/// don't apply to it
+fn waypoint_path(
+    action: &MouseAction,
+    waypoints: &[(i64, i64)],
+    targets: &[Option<&ElementRect>],
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if !matches!(action.interpolation, MouseInterpolation::CatmullRom) {
+        let mut positions = Vec::new();
+        for (leg, target) in waypoints.windows(2).zip(targets) {
+            positions.extend(create_path(action, leg[0], leg[1], *target, rng)?);
+        }
+        return Ok(positions);
+    }
+
+    let steps = waypoints
+        .windows(2)
+        .zip(targets)
+        .map(|(leg, target)| action.steps_between(leg[0], leg[1], *target))
+        .sum::<usize>()
+        .min(action.max_steps);
+    debug!(
+        waypoints = waypoints.len(),
+        steps, "creating catmull-rom path"
+    );
+    let mut positions = create_catmull_rom_steps(waypoints, steps);
+    if action.jitter_x > 0 || action.jitter_y > 0 {
+        jitter(action, &mut positions, rng);
+    }
+    if let (Some(threshold), Some(start)) = (action.merge_threshold_px, waypoints.first()) {
+        merge_close_points(&mut positions, *start, threshold);
+    }
+    if let (Some(max_speed), Some(start)) = (action.max_speed_px_per_step, waypoints.first()) {
+        limit_speed(&mut positions, *start, max_speed);
+    }
+    Ok(positions)
+}
+
+/// Drops every point within `threshold` pixels of the last one kept, `start`
+/// being the point the path is entered from
+///
+/// The final point replaces the last one kept when too close to it
+fn merge_close_points(positions: &mut Vec<(i64, i64)>, start: (i64, i64), threshold: f64) {
+    let Some(&last) = positions.last() else {
+        return;
+    };
+    let close =
+        |a: (i64, i64), b: (i64, i64)| ((b.0 - a.0) as f64).hypot((b.1 - a.1) as f64) < threshold;
+
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(positions.len());
+    for &point in &positions[..positions.len() - 1] {
+        if !close(merged.last().copied().unwrap_or(start), point) {
+            merged.push(point);
+        }
+    }
+    if merged.last().is_some_and(|kept| close(*kept, last)) {
+        merged.pop();
+    }
+    merged.push(last);
+    *positions = merged;
+}
+
+/// Splits every segment longer than `max_speed` pixels, `start` being the
+/// point the path is entered from
+fn limit_speed(positions: &mut Vec<(i64, i64)>, start: (i64, i64), max_speed: f64) {
+    let mut limited = Vec::with_capacity(positions.len());
+    let mut previous = start;
+    for &point in positions.iter() {
+        let (dx, dy) = ((point.0 - previous.0) as f64, (point.1 - previous.1) as f64);
+        let splits = (dx.hypot(dy) / max_speed).ceil() as usize;
+        for i in 1..splits {
+            let t = i as f64 / splits as f64;
+            limited.push((
+                previous.0 + (dx * t).round() as i64,
+                previous.1 + (dy * t).round() as i64,
+            ));
+        }
+        limited.push(point);
+        previous = point;
+    }
+    *positions = limited;
+}
+
+/// Sways the points perpendicular to the line between start and end along a
+/// sine wave over time, leaving the last point in place
+fn wobble(
+    positions: &mut [(i64, i64)],
+    start: (i64, i64),
+    end: (i64, i64),
+    amplitude: f64,
+    frequency: f64,
+    step_time_ms: f64,
+) {
+    let (dx, dy) = ((end.0 - start.0) as f64, (end.1 - start.1) as f64);
+    let distance = dx.hypot(dy);
+    if distance == 0.00 || positions.len() < 2 {
+        return;
+    }
+
+    let (normal_x, normal_y) = (dy / distance, -dx / distance);
+    let last = positions.len() - 1;
+    for (i, (x, y)) in positions.iter_mut().enumerate().take(last) {
+        let seconds = i as f64 * step_time_ms / 1000.00;
+        let offset = amplitude * (std::f64::consts::TAU * frequency * seconds).sin();
+        *x = (*x + (normal_x * offset).round() as i64).max(0);
+        *y = (*y + (normal_y * offset).round() as i64).max(0);
+    }
+}
+
+/// Points between two positions using the configured interpolation
+fn interpolate(
+    action: &MouseAction,
+    start: (i64, i64),
+    end: (i64, i64),
+    steps: usize,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    let positions = match &action.interpolation {
+        MouseInterpolation::Linear => {
+            let mut positions = match &action.ease_out {
+                Some(ease_out) => create_asymmetric_linear_steps(
+                    start.0,
+                    start.1,
+                    end.0,
+                    end.1,
+                    steps,
+                    &action.easing,
+                    ease_out,
+                )?,
+                None => create_eased_linear_steps(
+                    start.0,
+                    start.1,
+                    end.0,
+                    end.1,
+                    steps,
+                    &action.easing,
+                )?,
+            };
+            if action.curve_bias != 0.00 {
+                bow(&mut positions, start, end, action.curve_bias);
+            }
+            positions
+        }
+        MouseInterpolation::Spline => create_spline_steps_with_range(
+            start.0,
+            start.1,
+            end.0,
+            end.1,
+            steps,
+            action.spline_control_range,
+            rng,
+        )?,
+        interpolation => interpolation.generate(start, end, steps, rng)?,
+    };
+    Ok(positions)
+}
+
+/// Pushes the points sideways of the line between start and end, by `bias`
+/// times the distance at the midpoint and easing off to nothing at both ends,
+/// positive bows to the left of the direction of travel and negative to the right
+fn bow(positions: &mut [(i64, i64)], start: (i64, i64), end: (i64, i64), bias: f64) {
+    let (dx, dy) = ((end.0 - start.0) as f64, (end.1 - start.1) as f64);
+    let distance = dx.hypot(dy);
+    if distance == 0.00 || positions.len() < 2 {
+        return;
+    }
+
+    // Unit vector perpendicular to the direction of travel
+    let (normal_x, normal_y) = (dy / distance, -dx / distance);
+    let last = (positions.len() - 1) as f64;
+    for (i, (x, y)) in positions.iter_mut().enumerate() {
+        let t = i as f64 / last;
+        let offset = bias * distance * 4.00 * t * (1.00 - t);
+        *x = (*x + (normal_x * offset).round() as i64).max(0);
+        *y = (*y + (normal_y * offset).round() as i64).max(0);
+    }
+}
+
+/// A button action followed by the moves performed after it
+struct Stroke {
+    action: MouseButtonAction,
+    positions: Vec<(i64, i64)>,
+}
+
+impl Stroke {
+    fn new(action: MouseButtonAction, positions: Vec<(i64, i64)>) -> Self {
+        Stroke { action, positions }
+    }
+
+    /// How far through the stroke the point at `index` is, from 0.0 to 1.0
+    fn progress(&self, index: usize) -> f64 {
+        match self.positions.len() {
+            0 | 1 => 1.00,
+            len => index as f64 / (len - 1) as f64,
+        }
+    }
+}
+
+/// Sends every stroke followed by the end action, so held buttons stay pressed
+/// across the whole path
+///
+/// Everything is sent in a single batch unless the action asks for precise
+/// timing, in which case every move is sent on its own and paced to match the
+/// configured duration.
+///
+/// Returns the full path that was performed
+async fn perform_path(
+    driver: &WebDriver,
+    action: &MouseAction,
+    mut strokes: Vec<Stroke>,
+    end_action: &MouseButtonAction,
+    origin: (i64, i64),
+    cancel: Option<&CancellationToken>,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    clamp_to_viewport(driver, action, &mut strokes).await?;
+
+    let mut result = hold_modifiers(driver, &action.modifiers, true)
+        .await
+        .map_err(MouseActionError::from);
+    if result.is_ok() {
+        result = send_path(driver, action, origin, &strokes, end_action, cancel, rng).await;
+    }
+    // Released even after a failure so no key stays held down
+    let released = hold_modifiers(driver, &action.modifiers, false).await;
+
+    let mut result = result.and(released.map_err(MouseActionError::from));
+    if result.is_ok() && action.return_to_origin {
+        result = send_return(driver, action, &mut strokes, origin, cancel, rng).await;
+    }
+
+    if let Err(err) = result {
+        // Unknown how far the movement went, a cancelled one recorded where it stopped
+        if !matches!(err, MouseActionError::Cancelled) {
+            tracker::clear(driver);
+        }
+        return Err(err);
+    }
+
+    if let Some(last) = strokes
+        .iter()
+        .rev()
+        .find_map(|stroke| stroke.positions.last())
+    {
+        tracker::store(driver, *last);
+    }
+
+    Ok(strokes
+        .into_iter()
+        .flat_map(|stroke| stroke.positions)
+        .collect())
+}
+
+/// Moves from the end of the strokes back to the origin, appending the way
+/// back to them
+///
+/// Sent without modifiers, dwell nor end action, the movement being done
+async fn send_return(
+    driver: &WebDriver,
+    action: &MouseAction,
+    strokes: &mut Vec<Stroke>,
+    origin: (i64, i64),
+    cancel: Option<&CancellationToken>,
+    rng: &mut impl Rng,
+) -> MouseActionResult<()> {
+    let Some(last) = strokes
+        .iter()
+        .rev()
+        .find_map(|stroke| stroke.positions.last().copied())
+    else {
+        return Ok(());
+    };
+
+    let returning = returning(action);
+    let positions = create_path(&returning, last, origin, None, rng)?;
+    let mut way_back = vec![Stroke::new(MouseButtonAction::None, positions)];
+    clamp_to_viewport(driver, &returning, &mut way_back).await?;
+    send_path(
+        driver,
+        &returning,
+        last,
+        &way_back,
+        &MouseButtonAction::None,
+        cancel,
+        rng,
+    )
+    .await?;
+    strokes.append(&mut way_back);
+    Ok(())
+}
+
+/// The action moving back to the origin, without dwelling, overshooting,
+/// waiting for a target or easing out as there is no target to land on
+fn returning(action: &MouseAction) -> MouseAction {
+    MouseAction {
+        dwell_ms: None,
+        overshoot: None,
+        settle: None,
+        ease_out: None,
+        ..action.clone()
+    }
+}
+
+/// Keeps every point of the strokes inside the viewport when configured
+async fn clamp_to_viewport(
+    driver: &WebDriver,
+    action: &MouseAction,
+    strokes: &mut [Stroke],
+) -> MouseActionResult<()> {
+    if !action.clamp_to_viewport {
+        return Ok(());
+    }
+
+    // The last pixel inside the viewport is one short of its size
+    let (width, height) = tracker::viewport_size(driver).await?;
+    let (max_x, max_y) = ((width - 1).max(0), (height - 1).max(0));
+    for stroke in strokes {
+        for point in &mut stroke.positions {
+            *point = (point.0.clamp(0, max_x), point.1.clamp(0, max_y));
+        }
+    }
+    Ok(())
+}
+
+/// Factor from CSS to device pixels, `1.0` unless device pixel scaling is enabled
+async fn pixel_scale(driver: &WebDriver, action: &MouseAction) -> MouseActionResult<f64> {
+    if !action.device_pixel_scaling {
+        return Ok(1.00);
+    }
+
+    let ratio = tracker::device_pixel_ratio(driver).await?;
+    Ok(if ratio.is_finite() && ratio > 0.00 {
+        ratio
+    } else {
+        1.00
+    })
+}
+
+/// Point in CSS pixels scaled to the pixels the driver expects
+fn to_device(point: (i64, i64), scale: f64) -> (i64, i64) {
+    (
+        (point.0 as f64 * scale).round() as i64,
+        (point.1 as f64 * scale).round() as i64,
+    )
+}
+
+/// Presses or releases the modifier keys, the key state persists across
+/// requests so it wraps every batch of the movement
+async fn hold_modifiers(driver: &WebDriver, modifiers: &[Key], down: bool) -> WebDriverResult<()> {
+    if modifiers.is_empty() {
+        return Ok(());
+    }
+
+    let mut action_chain = driver.action_chain_with_delay(None, Some(0));
+    for modifier in modifiers {
+        action_chain = if down {
+            action_chain.key_down(*modifier)
+        } else {
+            action_chain.key_up(*modifier)
+        };
+    }
+    action_chain.perform().await
+}
+
+/// Whether the path needs raw pointer actions rather than an `ActionChain`
+fn uses_pointer_actions(
+    action: &MouseAction,
+    strokes: &[Stroke],
+    end_action: &MouseButtonAction,
+) -> bool {
+    // An `ActionChain` can't hold the button down within a click
+    let requires_pointer_actions = |button: &MouseButtonAction| {
+        button.requires_pointer_actions() || (action.click_hold_ms.is_some() && button.is_click())
+    };
+    action.pointer_type != PointerType::Mouse
+        || requires_pointer_actions(end_action)
+        || strokes
+            .iter()
+            .any(|stroke| requires_pointer_actions(&stroke.action))
+}
+
+/// Sends the strokes from `start` followed by the end action
+async fn send_path(
+    driver: &WebDriver,
+    action: &MouseAction,
+    start: (i64, i64),
+    strokes: &[Stroke],
+    end_action: &MouseButtonAction,
+    cancel: Option<&CancellationToken>,
+    rng: &mut impl Rng,
+) -> MouseActionResult<()> {
+    let pointer_actions = uses_pointer_actions(action, strokes, end_action);
+    let scale = pixel_scale(driver, action).await?;
+    let dwell = action.dwell(rng);
+    if action.precise_timing || action.mid_path_pauses.is_some() || action.cdp_moves {
+        let mut index = 0;
+        let step_time = Duration::from_secs_f64(action.paced_step_time_ms() / 1000.00);
+        let pauses = action.pause_points(strokes, rng);
+        let mut mover = Mover::new(driver, action, pointer_actions, scale, start).await;
+        let mut deadline = Instant::now();
+
+        // Touch and pen have their own input source, left wherever it last was
+        if action.pointer_type != PointerType::Mouse {
+            mover.move_to(driver, action, start, 0.00).await?;
+        }
+
+        for stroke in strokes {
+            mover
+                .button(driver, action, &stroke.action, action.click_hold(rng))
+                .await?;
+
+            for (i, point) in stroke.positions.iter().enumerate() {
+                trace!(x = point.0, y = point.1, "move");
+                mover
+                    .move_to(driver, action, *point, stroke.progress(i))
+                    .await?;
+                action.step(*point, index);
+                if pauses.contains(&index) {
+                    deadline += action.mid_path_pause(rng);
+                }
+                index += 1;
+
+                deadline += step_time;
+                if !sleep_unless_cancelled(deadline, cancel).await {
+                    tracker::store(driver, *point);
+                    return Err(MouseActionError::Cancelled);
+                }
+            }
+        }
+
+        if !sleep_unless_cancelled(Instant::now() + dwell, cancel).await {
+            // The whole path was sent, the mouse rests on its last point
+            if let Some(last) = strokes
+                .iter()
+                .rev()
+                .find_map(|stroke| stroke.positions.last())
+            {
+                tracker::store(driver, *last);
+            }
+            return Err(MouseActionError::Cancelled);
+        }
+        mover
+            .button(driver, action, end_action, action.click_hold(rng))
+            .await?;
+    } else {
+        // The end action goes in its own batch after pausing on the target
+        let batched_end = dwell.is_zero().then_some(end_action);
+        let batch = InputBatch::new(driver, action, pointer_actions);
+        batch_path(batch, action, start, strokes, batched_end, scale, rng)?
+            .perform_retrying(driver, action.retry.as_ref())
+            .await?;
+
+        if !dwell.is_zero() {
+            sleep(dwell).await;
+            if !matches!(end_action, MouseButtonAction::None) {
+                InputBatch::new(driver, action, pointer_actions)
+                    .button(end_action, action.click_hold(rng))?
+                    .perform_retrying(driver, action.retry.as_ref())
+                    .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds every stroke to the batch, its button action followed by its moves,
+/// then the end action when given
+///
+/// Any start action combines with any end action, a click at the origin and
+/// another at the target being as valid as a hold and its release
+fn batch_path(
+    mut batch: InputBatch,
+    action: &MouseAction,
+    start: (i64, i64),
+    strokes: &[Stroke],
+    end_action: Option<&MouseButtonAction>,
+    scale: f64,
+    rng: &mut impl Rng,
+) -> MouseActionResult<InputBatch> {
+    // Touch and pen have their own input source, left wherever it last was
+    if action.pointer_type != PointerType::Mouse {
+        let (x, y) = to_device(start, scale);
+        batch = batch.move_to(x, y, 0.00);
+    }
+
+    let mut index = 0;
+    for stroke in strokes {
+        batch = batch.button(&stroke.action, action.click_hold(rng))?;
+        for (i, point) in stroke.positions.iter().enumerate() {
+            trace!(x = point.0, y = point.1, "move");
+            let (x, y) = to_device(*point, scale);
+            batch = batch.move_to(x, y, stroke.progress(i));
+            action.step(*point, index);
+            index += 1;
+        }
+    }
+
+    match end_action {
+        Some(end_action) => batch.button(end_action, action.click_hold(rng)),
+        None => Ok(batch),
+    }
+}
+
+/// Where the moves of a paced path go, through CDP when the action asks for it
+/// and the browser supports it, as WebDriver actions otherwise
+enum Mover {
+    Actions {
+        pointer_actions: bool,
+        scale: f64,
+    },
+    /// The CDP pointer is apart from the WebDriver one, buttons are pressed
+    /// where the last CDP move left it
+    #[cfg(feature = "cdp")]
+    Cdp {
+        mouse: CdpMouse,
+        position: (i64, i64),
+    },
+}
+
+impl Mover {
+    #[cfg_attr(not(feature = "cdp"), allow(unused_variables))]
+    async fn new(
+        driver: &WebDriver,
+        action: &MouseAction,
+        pointer_actions: bool,
+        scale: f64,
+        start: (i64, i64),
+    ) -> Self {
+        #[cfg(feature = "cdp")]
+        if action.cdp_moves && action.pointer_type == PointerType::Mouse {
+            if let Some(mouse) = CdpMouse::connect(driver, &action.modifiers).await {
+                return Mover::Cdp {
+                    mouse,
+                    position: start,
+                };
+            }
+        }
+        Mover::Actions {
+            pointer_actions,
+            scale,
+        }
+    }
+
+    /// Performs the button action where the mouse is
+    async fn button(
+        &mut self,
+        driver: &WebDriver,
+        action: &MouseAction,
+        button: &MouseButtonAction,
+        hold_ms: Option<u64>,
+    ) -> MouseActionResult<()> {
+        if matches!(button, MouseButtonAction::None) {
+            return Ok(());
+        }
+
+        match self {
+            Mover::Actions {
+                pointer_actions, ..
+            } => {
+                InputBatch::new(driver, action, *pointer_actions)
+                    .button(button, hold_ms)?
+                    .perform_retrying(driver, action.retry.as_ref())
+                    .await?;
+            }
+            // Not retried, a press could be sent twice
+            #[cfg(feature = "cdp")]
+            Mover::Cdp { mouse, position } => mouse.button(button, *position, hold_ms).await?,
+        }
+        Ok(())
+    }
+
+    /// Moves to the point in CSS pixels, `progress` of the way through its stroke
+    async fn move_to(
+        &mut self,
+        driver: &WebDriver,
+        action: &MouseAction,
+        point: (i64, i64),
+        progress: f64,
+    ) -> MouseActionResult<()> {
+        match self {
+            Mover::Actions {
+                pointer_actions,
+                scale,
+            } => {
+                let (x, y) = to_device(point, *scale);
+                InputBatch::new(driver, action, *pointer_actions)
+                    .move_to(x, y, progress)
+                    .perform_retrying(driver, action.retry.as_ref())
+                    .await?;
+            }
+            #[cfg(feature = "cdp")]
+            Mover::Cdp { mouse, position } => {
+                retry(action.retry.as_ref(), || async {
+                    mouse
+                        .move_to(point.0, point.1)
+                        .await
+                        .map_err(MouseActionError::from)
+                })
+                .await?;
+                *position = point;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sleeps until the deadline, `false` when cancelled before reaching it
+async fn sleep_unless_cancelled(deadline: Instant, cancel: Option<&CancellationToken>) -> bool {
+    match cancel {
+        #[cfg(feature = "runtime-tokio")]
+        Some(cancel) => cancel
+            .run_until_cancelled(sleep_until(deadline))
+            .await
+            .is_some(),
+        #[cfg(not(feature = "runtime-tokio"))]
+        Some(cancel) => match *cancel {},
+        None => {
+            sleep_until(deadline).await;
+            true
+        }
+    }
+}
+
+/// Input sent to the browser in one request, through an `ActionChain` when
+/// possible or as raw pointer actions otherwise
+enum InputBatch {
+    Chain(ActionChain),
+    Pointer(PointerActions),
+}
+
+impl InputBatch {
+    fn new(driver: &WebDriver, action: &MouseAction, pointer_actions: bool) -> Self {
+        if pointer_actions {
+            InputBatch::Pointer(PointerActions::new(action.pointer_type))
+        } else {
+            InputBatch::Chain(driver.action_chain_with_delay(None, Some(0)))
+        }
+    }
+
+    /// Adds the button action, a hold only applies to clicks sent as pointer actions
+    fn button(self, action: &MouseButtonAction, hold_ms: Option<u64>) -> MouseActionResult<Self> {
+        Ok(match self {
+            InputBatch::Chain(action_chain) => InputBatch::Chain(action.action(action_chain)?),
+            InputBatch::Pointer(pointer_actions) => {
+                InputBatch::Pointer(action.pointer(pointer_actions, hold_ms))
+            }
+        })
+    }
+
+    /// Adds a move `progress` of the way through its stroke
+    fn move_to(self, x: i64, y: i64, progress: f64) -> Self {
+        match self {
+            InputBatch::Chain(action_chain) => InputBatch::Chain(action_chain.move_to(x, y)),
+            InputBatch::Pointer(pointer_actions) => {
+                InputBatch::Pointer(pointer_actions.move_to(x, y, progress))
+            }
+        }
+    }
+
+    async fn perform(&self, driver: &WebDriver) -> WebDriverResult<()> {
+        match self {
+            InputBatch::Chain(action_chain) => action_chain.perform().await,
+            InputBatch::Pointer(pointer_actions) => pointer_actions.perform(driver).await,
+        }
+    }
+
+    /// Performs the batch, sending it again after transient errors as the policy allows
+    ///
+    /// A batch failing partway is sent again whole, so its clicks may repeat
+    async fn perform_retrying(
+        self,
+        driver: &WebDriver,
+        policy: Option<&RetryPolicy>,
+    ) -> MouseActionResult<()> {
+        retry(policy, || async {
+            self.perform(driver).await.map_err(MouseActionError::from)
+        })
+        .await
+    }
+}
+
+impl MouseButtonAction {
+    /// Whether the action has no `ActionChain` equivalent and must be sent as raw pointer actions
+    fn requires_pointer_actions(&self) -> bool {
+        matches!(
+            self,
+            MouseButtonAction::RightHold
+                | MouseButtonAction::RightRelease
+                | MouseButtonAction::MiddleClick
+                | MouseButtonAction::MiddleHold
+                | MouseButtonAction::MiddleRelease
+        )
+    }
+
+    /// Whether the action presses and releases a button in one go
+    fn is_click(&self) -> bool {
+        matches!(
+            self,
+            MouseButtonAction::LeftClick
+                | MouseButtonAction::RightClick
+                | MouseButtonAction::DoubleClick
+                | MouseButtonAction::MiddleClick
+        )
+    }
+
+    /// Appends the action to the chain, failing for the actions only sent as pointer actions
+    fn action(&self, action_chain: ActionChain) -> MouseActionResult<ActionChain> {
+        Ok(match self {
+            MouseButtonAction::None => action_chain,
+            MouseButtonAction::LeftClick => action_chain.click(),
+            MouseButtonAction::LeftHold => action_chain.click_and_hold(),
+            MouseButtonAction::LeftRelease => action_chain.release(),
+            MouseButtonAction::RightClick => action_chain.context_click(),
+            MouseButtonAction::DoubleClick => action_chain.double_click(),
+            MouseButtonAction::RightHold
+            | MouseButtonAction::RightRelease
+            | MouseButtonAction::MiddleClick
+            | MouseButtonAction::MiddleHold
+            | MouseButtonAction::MiddleRelease => {
+                return Err(MouseActionError::ChainUnsupported(self.clone()))
             }
-            (x as i64, y as i64)
         })
-        .collect::<Vec<_>>()
+    }
+
+    fn pointer(&self, pointer_actions: PointerActions, hold_ms: Option<u64>) -> PointerActions {
+        match self {
+            MouseButtonAction::None => pointer_actions,
+            MouseButtonAction::LeftClick => pointer_actions.click(PointerButton::Left, hold_ms),
+            MouseButtonAction::LeftHold => pointer_actions.press(PointerButton::Left),
+            MouseButtonAction::LeftRelease => pointer_actions.release(PointerButton::Left),
+            MouseButtonAction::RightClick => pointer_actions.click(PointerButton::Right, hold_ms),
+            MouseButtonAction::RightHold => pointer_actions.press(PointerButton::Right),
+            MouseButtonAction::RightRelease => pointer_actions.release(PointerButton::Right),
+            MouseButtonAction::DoubleClick => pointer_actions
+                .click(PointerButton::Left, hold_ms)
+                .click(PointerButton::Left, hold_ms),
+            MouseButtonAction::MiddleClick => pointer_actions.click(PointerButton::Middle, hold_ms),
+            MouseButtonAction::MiddleHold => pointer_actions.press(PointerButton::Middle),
+            MouseButtonAction::MiddleRelease => pointer_actions.release(PointerButton::Middle),
+        }
+    }
+}
+
+fn jitter(action: &MouseAction, input: &mut [(i64, i64)], rng: &mut impl Rng) {
+    let len = input.len();
+    // Points within the decay steady down linearly towards the last point
+    let decay_len = ((len as f64 * action.jitter_decay.clamp(0.00, 1.00)).ceil() as usize).max(1);
+    let decay_start = len.saturating_sub(decay_len);
+
+    input.iter_mut().enumerate().for_each(|(i, (x, y))| {
+        // The last point is where the end action lands, it stays on the target
+        let scale = if i == len - 1 {
+            0.00
+        } else if action.jitter_decay > 0.00 && i >= decay_start {
+            (len - i - 1) as f64 / decay_len as f64
+        } else {
+            1.00
+        };
+        let amount_x = (action.jitter_x as f64 * scale).round() as i64;
+        let amount_y = (action.jitter_y as f64 * scale).round() as i64;
+
+        let add_jitter = rng.gen_bool(action.jitter_probability);
+        if add_jitter {
+            if amount_x > 0 {
+                *x += jitter_offset(&action.jitter_distribution, amount_x, rng);
+            }
+            if amount_y > 0 {
+                *y += jitter_offset(&action.jitter_distribution, amount_y, rng);
+            }
+        }
+    })
+}
+
+/// Offset of a jittered coordinate for a positive amount
+fn jitter_offset(distribution: &JitterDistribution, amount: i64, rng: &mut impl Rng) -> i64 {
+    match distribution {
+        JitterDistribution::Uniform => rng.gen_range(-amount..=amount),
+        JitterDistribution::Normal => gaussian(amount as f64 / 2.00, rng).round() as i64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use serde_json::Value;
+
+    use super::*;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> ElementRect {
+        ElementRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn curve_bias_bows_linear_paths_to_the_left() {
+        let path = |interpolation: MouseInterpolation, curve_bias: f64| {
+            let action = MouseAction::builder()
+                .interpolation(interpolation)
+                .easing(Easing::Plateau(0.00))
+                .curve_bias(curve_bias)
+                .build();
+            interpolate(&action, (100, 300), (300, 300), 9, &mut action.rng()).unwrap()
+        };
+
+        // Travelling right, so left of the direction of travel is up the page
+        let bowed = path(MouseInterpolation::Linear, 0.10);
+        assert_eq!(
+            (bowed[0], bowed[4], bowed[8]),
+            ((100, 300), (200, 280), (300, 300))
+        );
+        assert_eq!(path(MouseInterpolation::Linear, -0.10)[4], (200, 320));
+        assert_eq!(
+            path(MouseInterpolation::MinimumJerk, 0.10),
+            path(MouseInterpolation::MinimumJerk, 0.00)
+        );
+    }
+
+    #[test]
+    fn getters_read_back_the_builder() {
+        let action = MouseAction::builder()
+            .jitter_amount(4)
+            .wait_for_stable_rect(50, 1.50, 2_000)
+            .ease_in_out(Easing::EaseIn, Easing::EaseOut)
+            .sine_wobble(3.00, 2.00)
+            .build();
+        assert_eq!(action.jitter_amount(), (4, 4));
+        assert_eq!(action.settle(), Some((50, 1.50, 2_000)));
+        assert_eq!(action.ease_out(), Some(&Easing::EaseOut));
+        assert_eq!(action.sine_wobble(), Some((3.00, 2.00)));
+        assert_eq!(action.pacing(), &Pacing::Duration);
+        assert!(!action.fixed_steps());
+
+        let action = MouseAction::builder()
+            .jitter_x(2)
+            .jitter_y(7)
+            .steps(20)
+            .build();
+        assert_eq!(action.jitter_amount(), (2, 7));
+        assert!(action.fixed_steps());
+    }
+
+    #[test]
+    fn return_path_ends_on_the_origin() {
+        let action = MouseAction::builder()
+            .seed(7)
+            .jitter_amount(0)
+            .overshoot(0.30)
+            .ease_in_out(Easing::EaseIn, Easing::EaseOut)
+            .wait_for_stable_rect(50, 1.00, 500)
+            .dwell_range_ms(100, 300)
+            .build();
+        let returning = returning(&action);
+        assert_eq!(
+            (
+                returning.overshoot(),
+                returning.settle(),
+                returning.ease_out(),
+                returning.dwell_ms()
+            ),
+            (None, None, None, None)
+        );
+
+        let origin = (100, 50);
+        let path = create_path(&returning, (400, 300), origin, None, &mut returning.rng()).unwrap();
+        assert_eq!(path.last(), Some(&origin));
+        assert!(path.iter().all(|&(x, y)| x >= origin.0 && y >= origin.1));
+    }
+
+    #[test]
+    fn huge_duration_is_capped_at_max_steps() {
+        assert_eq!(
+            MouseAction::builder().duration_ms(u64::MAX).build().steps(),
+            5_000
+        );
+        assert_eq!(
+            MouseAction::builder()
+                .duration_ms(u64::MAX)
+                .fps(1000)
+                .build()
+                .steps(),
+            5_000
+        );
+    }
+
+    #[test]
+    fn try_build_reports_clamped_settings() {
+        let settings = |builder: MouseActionBuilder| {
+            builder
+                .try_build()
+                .unwrap_err()
+                .into_iter()
+                .map(|error| error.setting)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            settings(MouseAction::builder().anchor(Anchor::Offset { x: 1.50, y: 0.50 })),
+            ["anchor"]
+        );
+        assert_eq!(
+            settings(MouseAction::builder().easing(Easing::Plateau(2.00))),
+            ["easing"]
+        );
+        assert_eq!(
+            settings(
+                MouseAction::builder().interpolation(MouseInterpolation::Wind {
+                    gravity: f64::NAN,
+                    wind: 3.00,
+                })
+            ),
+            ["interpolation"]
+        );
+        assert_eq!(
+            settings(MouseActionBuilder {
+                dwell_ms: Some((300, 100)),
+                click_hold_ms: Some((120, 80)),
+                ..Default::default()
+            }),
+            ["dwell_ms", "click_hold_ms"]
+        );
+        assert!(MouseAction::builder()
+            .anchor(Anchor::Offset { x: 1.00, y: 0.00 })
+            .easing(Easing::Plateau(1.00))
+            .dwell_range_ms(100, 300)
+            .try_build()
+            .is_ok());
+    }
+
+    /// Fails when a dependency bump changes what a seed produces
+    #[cfg(not(feature = "spline"))]
+    #[test]
+    fn seeded_path_snapshot() {
+        let action = MouseAction::builder().seed(42).build();
+        let path =
+            paths::create_spline_steps_with_rng(100, 50, 400, 300, 8, &mut action.rng()).unwrap();
+        assert_eq!(
+            path,
+            [
+                (100, 50),
+                (158, 113),
+                (217, 167),
+                (275, 212),
+                (318, 248),
+                (345, 274),
+                (372, 291),
+                (400, 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn swapped_ranges_are_ordered() {
+        let action = MouseActionBuilder {
+            dwell_ms: Some((300, 100)),
+            reaction_delay_ms: Some((50, 20)),
+            click_hold_ms: Some((120, 80)),
+            ..Default::default()
+        }
+        .build();
+        assert_eq!(action.dwell_ms(), Some((100, 300)));
+        assert_eq!(action.reaction_delay_ms(), Some((20, 50)));
+        assert_eq!(action.click_hold_ms(), Some((80, 120)));
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let dwell = action.dwell(&mut rng);
+        assert!((100..=300).contains(&(dwell.as_millis() as u64)));
+    }
+
+    #[test]
+    fn landing_stays_inside_the_element() {
+        let target = rect(100.00, 200.00, 40.00, 20.00);
+        let mut rng = StdRng::seed_from_u64(1);
+        for anchor in [
+            Anchor::Right,
+            Anchor::Bottom,
+            Anchor::BottomRight,
+            Anchor::Center,
+        ] {
+            for distribution in [LandingDistribution::Uniform, LandingDistribution::Gaussian] {
+                let action = MouseAction::builder()
+                    .anchor(anchor.clone())
+                    .landing_distribution(distribution)
+                    .landing_spread(1.00)
+                    .build();
+                for _ in 0..200 {
+                    let (x, y) = landing_point(&action, &target, &mut rng);
+                    assert!((100..140).contains(&x), "{anchor:?} landed at x {x}");
+                    assert!((200..220).contains(&y), "{anchor:?} landed at y {y}");
+                }
+            }
+        }
+
+        let action = MouseAction::builder()
+            .anchor(Anchor::BottomRight)
+            .landing_spread(0.00)
+            .build();
+        assert_eq!(landing_point(&action, &target, &mut rng), (139, 219));
+    }
+
+    /// Pointer actions of the path from `(5, 5)` between the two actions
+    fn pointer_path(
+        pointer_type: PointerType,
+        start_action: MouseButtonAction,
+        end_action: MouseButtonAction,
+    ) -> Vec<Value> {
+        let action = MouseAction::builder().pointer_type(pointer_type).build();
+        let strokes = [Stroke::new(start_action, vec![(10, 10), (20, 20)])];
+        let batch = InputBatch::Pointer(PointerActions::new(pointer_type));
+        let mut rng = StdRng::seed_from_u64(1);
+        let batch = batch_path(
+            batch,
+            &action,
+            (5, 5),
+            &strokes,
+            Some(&end_action),
+            1.00,
+            &mut rng,
+        )
+        .unwrap();
+        let InputBatch::Pointer(pointer_actions) = batch else {
+            unreachable!()
+        };
+        pointer_actions.actions().to_vec()
+    }
+
+    /// Type and button of every mouse pointer action of the path between the two actions
+    fn sequence(
+        start_action: MouseButtonAction,
+        end_action: MouseButtonAction,
+    ) -> Vec<(String, Option<u64>)> {
+        pointer_path(PointerType::Mouse, start_action, end_action)
+            .iter()
+            .map(|action| {
+                (
+                    action["type"].as_str().unwrap().to_string(),
+                    action.get("button").and_then(|button| button.as_u64()),
+                )
+            })
+            .collect()
+    }
+
+    fn step(kind: &str, button: Option<u64>) -> (String, Option<u64>) {
+        (kind.to_string(), button)
+    }
+
+    #[test]
+    fn click_at_the_origin_and_right_click_at_the_target() {
+        assert_eq!(
+            sequence(MouseButtonAction::LeftClick, MouseButtonAction::RightClick),
+            [
+                step("pointerDown", Some(0)),
+                step("pointerUp", Some(0)),
+                step("pointerMove", None),
+                step("pointerMove", None),
+                step("pointerDown", Some(2)),
+                step("pointerUp", Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn hold_at_the_origin_and_release_at_the_target() {
+        assert_eq!(
+            sequence(MouseButtonAction::LeftHold, MouseButtonAction::LeftRelease),
+            [
+                step("pointerDown", Some(0)),
+                step("pointerMove", None),
+                step("pointerMove", None),
+                step("pointerUp", Some(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn double_click_at_the_target() {
+        assert_eq!(
+            sequence(MouseButtonAction::None, MouseButtonAction::DoubleClick),
+            [
+                step("pointerMove", None),
+                step("pointerMove", None),
+                step("pointerDown", Some(0)),
+                step("pointerUp", Some(0)),
+                step("pointerDown", Some(0)),
+                step("pointerUp", Some(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn touch_moves_to_the_start_before_pressing() {
+        let actions = pointer_path(
+            PointerType::Touch,
+            MouseButtonAction::LeftHold,
+            MouseButtonAction::LeftRelease,
+        );
+        assert_eq!(actions[0]["type"], "pointerMove");
+        assert_eq!(
+            (actions[0]["x"].as_i64(), actions[0]["y"].as_i64()),
+            (Some(5), Some(5))
+        );
+        assert_eq!(actions[1]["type"], "pointerDown");
+    }
+
+    #[test]
+    fn zero_size_rect_has_no_area() {
+        assert!(!has_area(&rect(10.00, 10.00, 0.00, 20.00)));
+        assert!(!has_area(&rect(10.00, 10.00, 20.00, 0.00)));
+        assert!(has_area(&rect(10.00, 10.00, 0.50, 0.50)));
+    }
+
+    #[test]
+    fn scrolled_rect_moves_into_the_viewport() {
+        let moved = document_to_viewport(rect(100.00, 1500.00, 40.00, 20.00), (0.00, 1200.00));
+        assert_eq!((moved.x, moved.y), (100.00, 300.00));
+        assert_eq!((moved.width, moved.height), (40.00, 20.00));
+
+        let unscrolled = document_to_viewport(rect(100.00, 150.00, 40.00, 20.00), (0.00, 0.00));
+        assert_eq!((unscrolled.x, unscrolled.y), (100.00, 150.00));
+    }
 }
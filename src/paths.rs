@@ -0,0 +1,716 @@
+//! Path generators used by [`MouseActionExt`], usable on their own to inspect,
+//! benchmark or visualize paths without a browser.
+//!
+//! Every generator returns `steps` points from the start to the end position,
+//! with negative coordinates floored to `0`, or [`MouseActionError::Interpolation`]
+//! when the curve cannot be built from the given points. Zero steps is such a
+//! degenerate input, while a start on the end is that single point repeated.
+//!
+//! The B-spline of [`MouseInterpolation::Spline`] and the plateau easing come
+//! from `enterpolation` with the `spline` feature, without it they are replaced
+//! by hand-rolled approximations, which never fail, so without the feature
+//! zero steps is the only [`MouseActionError::Interpolation`].
+//!
+//! [`MouseActionExt`]: crate::MouseActionExt
+//! [`MouseActionError::Interpolation`]: crate::MouseActionError::Interpolation
+
+use std::fmt;
+
+#[cfg(feature = "spline")]
+use enterpolation::bezier::Bezier;
+#[cfg(feature = "spline")]
+use enterpolation::bspline::BSpline;
+#[cfg(feature = "spline")]
+use enterpolation::{easing, linear::Linear, Curve};
+use rand::{thread_rng, Rng, RngCore};
+
+use crate::error::MouseActionResult;
+use crate::{Easing, MouseInterpolation};
+
+/// Source of the points of a movement, implement it to plug a custom path
+/// algorithm in through [`MouseInterpolation::Custom`]
+///
+/// `rng` is seeded when the action has a seed, drawing every random choice
+/// from it keeps seeded paths reproducible
+pub trait PathGenerator: fmt::Debug + Send + Sync {
+    /// Returns `steps` points from `start` to `end`
+    fn generate(
+        &self,
+        start: (i64, i64),
+        end: (i64, i64),
+        steps: usize,
+        rng: &mut dyn RngCore,
+    ) -> MouseActionResult<Vec<(i64, i64)>>;
+}
+
+impl PathGenerator for MouseInterpolation {
+    fn generate(
+        &self,
+        (start_x, start_y): (i64, i64),
+        (end_x, end_y): (i64, i64),
+        steps: usize,
+        mut rng: &mut dyn RngCore,
+    ) -> MouseActionResult<Vec<(i64, i64)>> {
+        match self {
+            MouseInterpolation::Linear => {
+                create_linear_steps(start_x, start_y, end_x, end_y, steps)
+            }
+            MouseInterpolation::Spline => {
+                create_spline_steps_with_rng(start_x, start_y, end_x, end_y, steps, &mut rng)
+            }
+            MouseInterpolation::CubicBezier { c1, c2 } => {
+                create_cubic_bezier_steps(start_x, start_y, end_x, end_y, *c1, *c2, steps)
+            }
+            MouseInterpolation::MinimumJerk => {
+                Ok(create_min_jerk_steps(start_x, start_y, end_x, end_y, steps))
+            }
+            MouseInterpolation::Wind { gravity, wind } => Ok(create_wind_steps(
+                start_x, start_y, end_x, end_y, *gravity, *wind, steps, &mut rng,
+            )),
+            MouseInterpolation::CatmullRom => {
+                // A random waypoint within the bounding box gives the curve its bend
+                let waypoint = (
+                    rng.gen_range(start_x.min(end_x)..=start_x.max(end_x)),
+                    rng.gen_range(start_y.min(end_y)..=start_y.max(end_y)),
+                );
+                Ok(create_catmull_rom_steps(
+                    &[(start_x, start_y), waypoint, (end_x, end_y)],
+                    steps,
+                ))
+            }
+            MouseInterpolation::Custom(generator) => {
+                generator.generate((start_x, start_y), (end_x, end_y), steps, rng)
+            }
+        }
+    }
+}
+
+/// Path along a random curve, the x axis is interpolated linearly and the y
+/// axis with a B-spline through a random control point between start and end
+///
+/// The x coordinate of the control point stays within the bounding box, so
+/// the x coordinates are monotonic and the path never reverses on the x axis
+///
+/// Without the `spline` feature the y axis follows a quadratic Bézier curve
+/// through the same control point instead
+pub fn create_spline_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    create_spline_steps_with_rng(start_x, start_y, end_x, end_y, steps, &mut thread_rng())
+}
+
+/// Same as [`create_spline_steps`] drawing the control point from `rng`
+pub fn create_spline_steps_with_rng(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    create_spline_steps_with_range(start_x, start_y, end_x, end_y, steps, 1.00, rng)
+}
+
+/// Same as [`create_spline_steps_with_rng`] drawing the control point within
+/// the middle `control_range` fraction of the bounding box on each axis
+///
+/// `1.0` spans the whole bounding box, smaller values tame the curve and
+/// larger ones let it swing beyond the box on the y axis, the x coordinate
+/// being kept within the box so x stays monotonic
+#[cfg(feature = "spline")]
+pub fn create_spline_steps_with_range(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+    control_range: f64,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if let Some(path) = degenerate_path((start_x, start_y), (end_x, end_y), steps) {
+        return path;
+    }
+    let x_min = start_x.min(end_x);
+    let x_max = start_x.max(end_x);
+    let y_min = start_y.min(end_y);
+    let y_max = start_y.max(end_y);
+
+    // Inclusive ranges, aligned start and end points would otherwise be an empty range.
+    // Kept between start and end, so x only ever progresses towards the end.
+    let x_offset_one = control_coordinate(x_min, x_max, control_range, rng).clamp(x_min, x_max);
+    let y_offset_one = control_coordinate(y_min, y_max, control_range, rng);
+
+    let linear_x = Linear::builder()
+        .elements([start_x as f64, x_offset_one as f64, end_x as f64])
+        .equidistant()
+        .normalized()
+        .easing(easing::Plateau::new(0.00))
+        .build()
+        .map_err(interpolation_error)?;
+
+    let bezier_y = Bezier::builder()
+        .elements([start_y as f64, y_offset_one as f64, end_y as f64])
+        .normalized::<f64>()
+        .constant::<3>()
+        .build()
+        .map_err(interpolation_error)?;
+
+    let bspline_y = BSpline::builder()
+        .clamped()
+        .elements([start_y as f64, y_offset_one as f64, end_y as f64])
+        .knots(bezier_y.domain())
+        .dynamic()
+        .build()
+        .map_err(interpolation_error)?;
+
+    Ok(linear_x
+        .take(steps)
+        .zip(bspline_y.take(steps))
+        .map(|(x, y)| to_point(x, y))
+        .collect::<Vec<_>>())
+}
+
+/// Same as [`create_spline_steps_with_rng`] drawing the control point within
+/// the middle `control_range` fraction of the bounding box on each axis
+///
+/// `1.0` spans the whole bounding box, smaller values tame the curve and
+/// larger ones let it swing beyond the box on the y axis, the x coordinate
+/// being kept within the box so x stays monotonic
+#[cfg(not(feature = "spline"))]
+pub fn create_spline_steps_with_range(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+    control_range: f64,
+    rng: &mut impl Rng,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if let Some(path) = degenerate_path((start_x, start_y), (end_x, end_y), steps) {
+        return path;
+    }
+    let x_min = start_x.min(end_x);
+    let x_max = start_x.max(end_x);
+    let y_min = start_y.min(end_y);
+    let y_max = start_y.max(end_y);
+
+    // Kept between start and end, so x only ever progresses towards the end
+    let x_offset_one =
+        control_coordinate(x_min, x_max, control_range, rng).clamp(x_min, x_max) as f64;
+    let y_offset_one = control_coordinate(y_min, y_max, control_range, rng) as f64;
+    let (start_x, start_y) = (start_x as f64, start_y as f64);
+    let (end_x, end_y) = (end_x as f64, end_y as f64);
+
+    // x goes linearly through the control point halfway, y bends towards it
+    Ok(moments(steps)
+        .map(|t| {
+            let x = if t < 0.50 {
+                start_x + (x_offset_one - start_x) * t * 2.00
+            } else {
+                x_offset_one + (end_x - x_offset_one) * (t - 0.50) * 2.00
+            };
+            let y = (1.00 - t).powi(2) * start_y
+                + 2.00 * (1.00 - t) * t * y_offset_one
+                + t.powi(2) * end_y;
+            to_point(x, y)
+        })
+        .collect::<Vec<_>>())
+}
+
+/// Path of the inputs without a curve to build, `None` for regular ones
+///
+/// Zero steps has no point to return, a start on the end is that point at
+/// every step
+fn degenerate_path(
+    start: (i64, i64),
+    end: (i64, i64),
+    steps: usize,
+) -> Option<MouseActionResult<Vec<(i64, i64)>>> {
+    if steps == 0 {
+        return Some(Err(crate::MouseActionError::Interpolation(
+            "no steps to interpolate".to_string(),
+        )));
+    }
+    (start == end).then(|| Ok(vec![to_point(start.0 as f64, start.1 as f64); steps]))
+}
+
+/// Random coordinate within the middle `range` fraction of `min..=max`, a
+/// negative or NaN range being the center
+fn control_coordinate(min: i64, max: i64, range: f64, rng: &mut impl Rng) -> i64 {
+    let range = if range.is_nan() {
+        0.00
+    } else {
+        range.max(0.00)
+    };
+    if range == 1.00 {
+        return rng.gen_range(min..=max);
+    }
+
+    let center = (min + max) as f64 / 2.00;
+    let half_span = (max - min) as f64 * range / 2.00;
+    rng.gen_range((center - half_span).round() as i64..=(center + half_span).round() as i64)
+}
+
+/// Straight path easing in and out of the start and end points
+pub fn create_linear_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    create_eased_linear_steps(start_x, start_y, end_x, end_y, steps, &Easing::default())
+}
+
+/// Straight path whose progress along the line follows `easing`, the
+/// [`Easing::Plateau`] default being the same path as [`create_linear_steps`]
+///
+/// [`Easing::Plateau`]: crate::Easing::Plateau
+pub fn create_eased_linear_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+    easing: &Easing,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if let Some(path) = degenerate_path((start_x, start_y), (end_x, end_y), steps) {
+        return path;
+    }
+    let progress = eased_progress(easing, steps)?;
+    Ok(linear_steps_along(start_x, start_y, end_x, end_y, progress))
+}
+
+/// Straight path accelerating following `ease_in` and decelerating following
+/// `ease_out`, the progress shifting smoothly from one to the other along the path
+pub fn create_asymmetric_linear_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+    ease_in: &Easing,
+    ease_out: &Easing,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if let Some(path) = degenerate_path((start_x, start_y), (end_x, end_y), steps) {
+        return path;
+    }
+    let last = steps.saturating_sub(1).max(1) as f64;
+    let progress = eased_progress(ease_in, steps)?
+        .into_iter()
+        .zip(eased_progress(ease_out, steps)?)
+        .enumerate()
+        .map(|(i, (ease_in, ease_out))| {
+            let t = if steps == 1 { 1.00 } else { i as f64 / last };
+            // Smoothstep weight, the start follows `ease_in` and the end `ease_out`
+            let weight = t * t * (3.00 - 2.00 * t);
+            ease_in * (1.00 - weight) + ease_out * weight
+        })
+        .collect();
+    Ok(linear_steps_along(start_x, start_y, end_x, end_y, progress))
+}
+
+/// Points at each progress along the straight line from start to end
+fn linear_steps_along(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    progress: Vec<f64>,
+) -> Vec<(i64, i64)> {
+    let delta_x = (end_x - start_x) as f64;
+    let delta_y = (end_y - start_y) as f64;
+
+    progress
+        .into_iter()
+        .map(|progress| {
+            to_point(
+                start_x as f64 + delta_x * progress,
+                start_y as f64 + delta_y * progress,
+            )
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Progress from `0.0` to `1.0` at `steps` evenly spaced moments
+fn eased_progress(easing: &Easing, steps: usize) -> MouseActionResult<Vec<f64>> {
+    let ease = match easing {
+        Easing::Plateau(strength) => return plateau_progress(strength.clamp(0.00, 1.00), steps),
+        Easing::Smoothstep => |t: f64| t * t * (3.00 - 2.00 * t),
+        Easing::Sine => |t: f64| (1.00 - (t * std::f64::consts::PI).cos()) / 2.00,
+        Easing::EaseIn => |t: f64| t * t,
+        Easing::EaseOut => |t: f64| 1.00 - (1.00 - t) * (1.00 - t),
+    };
+
+    Ok(moments(steps).map(ease).collect())
+}
+
+#[cfg(feature = "spline")]
+fn plateau_progress(strength: f64, steps: usize) -> MouseActionResult<Vec<f64>> {
+    Ok(Linear::builder()
+        .elements([0.00, 1.00])
+        .equidistant::<f64>()
+        .normalized()
+        .easing(easing::Plateau::new(strength))
+        .build()
+        .map_err(interpolation_error)?
+        .take(steps)
+        .collect())
+}
+
+/// Accelerates quadratically over the first `strength / 2` of the time,
+/// moves at a constant speed and decelerates over the last `strength / 2`
+#[cfg(not(feature = "spline"))]
+fn plateau_progress(strength: f64, steps: usize) -> MouseActionResult<Vec<f64>> {
+    let ramp = strength / 2.00;
+    let speed = 1.00 / (1.00 - ramp);
+    Ok(moments(steps)
+        .map(|t| {
+            if ramp == 0.00 {
+                t
+            } else if t < ramp {
+                speed * t * t / (2.00 * ramp)
+            } else if t <= 1.00 - ramp {
+                speed * (t - ramp / 2.00)
+            } else {
+                1.00 - speed * (1.00 - t).powi(2) / (2.00 * ramp)
+            }
+        })
+        .collect())
+}
+
+/// `steps` evenly spaced moments from `0.0` to `1.0`, a single step being the end
+fn moments(steps: usize) -> impl Iterator<Item = f64> {
+    let last = steps.saturating_sub(1).max(1) as f64;
+    (0..steps).map(move |i| if steps == 1 { 1.00 } else { i as f64 / last })
+}
+
+/// Path along a cubic Bézier curve, see [`MouseInterpolation::CubicBezier`]
+/// for how the control points are expressed
+///
+/// [`MouseInterpolation::CubicBezier`]: crate::MouseInterpolation::CubicBezier
+pub fn create_cubic_bezier_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    c1: (f64, f64),
+    c2: (f64, f64),
+    steps: usize,
+) -> MouseActionResult<Vec<(i64, i64)>> {
+    if let Some(path) = degenerate_path((start_x, start_y), (end_x, end_y), steps) {
+        return path;
+    }
+    let width = (end_x - start_x) as f64;
+    let height = (end_y - start_y) as f64;
+    let xs = [
+        start_x as f64,
+        start_x as f64 + width * c1.0,
+        start_x as f64 + width * c2.0,
+        end_x as f64,
+    ];
+    let ys = [
+        start_y as f64,
+        start_y as f64 + height * c1.1,
+        start_y as f64 + height * c2.1,
+        end_y as f64,
+    ];
+
+    // Bernstein form of the cubic curve
+    let cubic = |[p0, p1, p2, p3]: [f64; 4], t: f64| {
+        let u = 1.00 - t;
+        u.powi(3) * p0 + 3.00 * u.powi(2) * t * p1 + 3.00 * u * t.powi(2) * p2 + t.powi(3) * p3
+    };
+    Ok(moments(steps)
+        .map(|t| to_point(cubic(xs, t), cubic(ys, t)))
+        .collect::<Vec<_>>())
+}
+
+/// Path following the minimum-jerk profile from motor-control research, the
+/// position along the straight line between start and end at `t` is
+/// `10t³ - 15t⁴ + 6t⁵`, accelerating and decelerating smoothly without randomness
+pub fn create_min_jerk_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    steps: usize,
+) -> Vec<(i64, i64)> {
+    let delta_x = (end_x - start_x) as f64;
+    let delta_y = (end_y - start_y) as f64;
+    let last = steps.saturating_sub(1).max(1) as f64;
+
+    (0..steps)
+        .map(|i| {
+            let t = if steps == 1 { 1.00 } else { i as f64 / last };
+            let progress = t.powi(3) * (10.00 - 15.00 * t + 6.00 * t.powi(2));
+            to_point(
+                start_x as f64 + delta_x * progress,
+                start_y as f64 + delta_y * progress,
+            )
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Path along a uniform Catmull-Rom spline passing through every waypoint in
+/// order, so the direction changes smoothly at each of them
+///
+/// The `steps` points are spread over the segments following their length,
+/// the first and last waypoints being the start and end
+pub fn create_catmull_rom_steps(waypoints: &[(i64, i64)], steps: usize) -> Vec<(i64, i64)> {
+    let points = waypoints
+        .iter()
+        .map(|(x, y)| (*x as f64, *y as f64))
+        .collect::<Vec<_>>();
+    if points.len() < 2 {
+        return waypoints.iter().copied().cycle().take(steps).collect();
+    }
+
+    let segments = points.len() - 1;
+    let lengths = points
+        .windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1))
+        .collect::<Vec<_>>();
+    let total: f64 = lengths.iter().sum();
+
+    moments(steps)
+        .map(|t| {
+            // Segment at this fraction of the length, equal shares when nothing moves
+            let (segment, local) = if total == 0.00 {
+                let position = t * segments as f64;
+                let segment = (position as usize).min(segments - 1);
+                (segment, position - segment as f64)
+            } else {
+                let mut remaining = t * total;
+                let mut segment = 0;
+                while segment < segments - 1 && remaining > lengths[segment] {
+                    remaining -= lengths[segment];
+                    segment += 1;
+                }
+                let local = if lengths[segment] == 0.00 {
+                    1.00
+                } else {
+                    (remaining / lengths[segment]).min(1.00)
+                };
+                (segment, local)
+            };
+
+            // The ends are their own outer neighbours
+            let p0 = points[segment.saturating_sub(1)];
+            let p1 = points[segment];
+            let p2 = points[segment + 1];
+            let p3 = points[(segment + 2).min(segments)];
+            let catmull_rom = |p0: f64, p1: f64, p2: f64, p3: f64| {
+                0.50 * (2.00 * p1
+                    + (p2 - p0) * local
+                    + (2.00 * p0 - 5.00 * p1 + 4.00 * p2 - p3) * local.powi(2)
+                    + (3.00 * p1 - p0 - 3.00 * p2 + p3) * local.powi(3))
+            };
+            to_point(
+                catmull_rom(p0.0, p1.0, p2.0, p3.0).round(),
+                catmull_rom(p0.1, p1.1, p2.1, p3.1).round(),
+            )
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Path simulated with the WindMouse algorithm, the cursor is pulled towards
+/// the end by `gravity` while `wind` pushes it around randomly, fading out as
+/// it gets close to the end
+///
+/// The simulation runs for at most ten times `steps` iterations and is then
+/// resampled to at most `steps` points, always finishing on the end point.
+#[allow(clippy::too_many_arguments)]
+pub fn create_wind_steps(
+    start_x: i64,
+    start_y: i64,
+    end_x: i64,
+    end_y: i64,
+    gravity: f64,
+    wind: f64,
+    steps: usize,
+    rng: &mut impl Rng,
+) -> Vec<(i64, i64)> {
+    if steps == 0 {
+        return Vec::new();
+    }
+
+    let sqrt3 = 3.00_f64.sqrt();
+    let sqrt5 = 5.00_f64.sqrt();
+    let (end_x, end_y) = (end_x as f64, end_y as f64);
+    let (mut x, mut y) = (start_x as f64, start_y as f64);
+
+    // The velocity settles between half and all of the max step, so this
+    // covers the distance in about the requested number of steps
+    let distance = (end_x - x).hypot(end_y - y);
+    let mut max_step = (distance / (0.75 * steps as f64)).max(1.00);
+    let target_area = max_step * 2.00;
+
+    let (mut wind_x, mut wind_y) = (0.00, 0.00);
+    let (mut velocity_x, mut velocity_y) = (0.00, 0.00);
+    let mut trail = Vec::new();
+
+    for _ in 0..steps.saturating_mul(10) {
+        let distance = (end_x - x).hypot(end_y - y);
+        if distance < 1.00 {
+            break;
+        }
+
+        let wind = wind.min(distance).max(0.00);
+        if distance >= target_area {
+            wind_x = wind_x / sqrt3 + rng.gen_range(-wind..=wind) / sqrt5;
+            wind_y = wind_y / sqrt3 + rng.gen_range(-wind..=wind) / sqrt5;
+        } else {
+            wind_x /= sqrt3;
+            wind_y /= sqrt3;
+            if max_step < 3.00 {
+                max_step = rng.gen_range(3.00..=6.00);
+            } else {
+                max_step /= sqrt5;
+            }
+        }
+
+        velocity_x += wind_x + gravity * (end_x - x) / distance;
+        velocity_y += wind_y + gravity * (end_y - y) / distance;
+
+        let velocity = velocity_x.hypot(velocity_y);
+        if velocity > max_step {
+            let clipped = rng.gen_range(max_step / 2.00..=max_step);
+            velocity_x = velocity_x / velocity * clipped;
+            velocity_y = velocity_y / velocity * clipped;
+        }
+
+        x += velocity_x;
+        y += velocity_y;
+        trail.push((x, y));
+    }
+
+    // Keep evenly spaced points of the trail counted back from its end, so the
+    // end point is always kept
+    trail.push((end_x, end_y));
+    if trail.len() > steps {
+        let last = (trail.len() - 1) as f64;
+        let divisor = steps.saturating_sub(1).max(1) as f64;
+        trail = (0..steps)
+            .map(|i| trail[(last - (steps - 1 - i) as f64 * last / divisor).round() as usize])
+            .collect();
+    }
+
+    trail
+        .into_iter()
+        .map(|(x, y)| to_point(x.round(), y.round()))
+        .collect::<Vec<_>>()
+}
+
+/// Points as CSV with an `x,y` header and one point per line
+pub fn path_to_csv(points: &[(i64, i64)]) -> String {
+    let mut csv = String::from("x,y\n");
+    for (x, y) in points {
+        csv.push_str(&format!("{x},{y}\n"));
+    }
+    csv
+}
+
+/// Points as an SVG polyline on a canvas of the given size, usually the
+/// viewport size, to eyeball the shape of a path
+pub fn path_to_svg(points: &[(i64, i64)], width: u32, height: u32) -> String {
+    let polyline = points
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}"><polyline points="{polyline}" fill="none" stroke="black" stroke-width="1"/></svg>"#
+    )
+}
+
+fn to_point(mut x: f64, mut y: f64) -> (i64, i64) {
+    if x.is_sign_negative() {
+        x = 0.00;
+    }
+    if y.is_sign_negative() {
+        y = 0.00;
+    }
+    (x as i64, y as i64)
+}
+
+#[cfg(feature = "spline")]
+fn interpolation_error(err: impl std::fmt::Display) -> crate::MouseActionError {
+    crate::MouseActionError::Interpolation(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn spline_to_vertically_aligned_target() {
+        let path =
+            create_spline_steps_with_rng(100, 50, 100, 400, 40, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+        assert_eq!(path.len(), 40);
+    }
+
+    #[test]
+    fn spline_x_is_monotonic() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for control_range in [0.00, 0.50, 1.00, 1.50, 3.00] {
+            for _ in 0..50 {
+                let forward =
+                    create_spline_steps_with_range(10, 300, 600, 40, 60, control_range, &mut rng)
+                        .unwrap();
+                assert!(forward.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+                let backward =
+                    create_spline_steps_with_range(600, 40, 10, 300, 60, control_range, &mut rng)
+                        .unwrap();
+                assert!(backward.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+            }
+        }
+    }
+
+    #[test]
+    fn negative_control_range_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for control_range in [-1.00, f64::NAN] {
+            let path =
+                create_spline_steps_with_range(10, 300, 600, 40, 30, control_range, &mut rng)
+                    .unwrap();
+            assert_eq!(path.len(), 30);
+        }
+    }
+
+    #[test]
+    fn zero_steps_is_an_error() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for result in [
+            create_linear_steps(5, 5, 5, 5, 0),
+            create_linear_steps(5, 5, 80, 40, 0),
+            create_spline_steps_with_rng(5, 5, 5, 5, 0, &mut rng),
+            create_spline_steps_with_rng(5, 5, 80, 40, 0, &mut rng),
+        ] {
+            assert!(matches!(
+                result,
+                Err(crate::MouseActionError::Interpolation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn single_point_input_is_that_point() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(create_linear_steps(5, 5, 5, 5, 1).unwrap(), [(5, 5)]);
+        assert_eq!(create_linear_steps(5, 5, 5, 5, 3).unwrap(), [(5, 5); 3]);
+        assert_eq!(
+            create_spline_steps_with_rng(5, 5, 5, 5, 1, &mut rng).unwrap(),
+            [(5, 5)]
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Raw W3C pointer actions for input the thirtyfour `ActionChain` cannot express,
+//! such as pressing the middle mouse button or scrolling the wheel.
+
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use thirtyfour::error::WebDriverResult;
+use thirtyfour::{ExtensionCommand, RequestMethod, WebDriver, WebElement};
+
+use crate::PointerType;
+
+/// Shares the id used by the thirtyfour `ActionChain`, so both drive the same input source
+const POINTER_ID: &str = "pointer";
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PointerButton {
+    Left = 0,
+    Middle = 1,
+    Right = 2,
+}
+
+#[derive(Debug)]
+pub(crate) struct PointerActions {
+    pointer_type: PointerType,
+    actions: Vec<Value>,
+}
+
+impl PointerActions {
+    pub(crate) fn new(pointer_type: PointerType) -> Self {
+        PointerActions {
+            pointer_type,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Moves `progress` of the way through a stroke, which sets the pressure
+    /// of a pen
+    pub(crate) fn move_to(mut self, x: i64, y: i64, progress: f64) -> Self {
+        let mut action = json!({
+            "type": "pointerMove",
+            "duration": 0,
+            "origin": "viewport",
+            "x": x,
+            "y": y,
+        });
+        self.pen_pressure(&mut action, progress);
+        self.actions.push(action);
+        self
+    }
+
+    pub(crate) fn press(mut self, button: PointerButton) -> Self {
+        let mut action = json!({
+            "type": "pointerDown",
+            "button": button as u8,
+        });
+        self.pen_pressure(&mut action, 0.00);
+        self.actions.push(action);
+        self
+    }
+
+    pub(crate) fn release(mut self, button: PointerButton) -> Self {
+        self.actions.push(json!({
+            "type": "pointerUp",
+            "button": button as u8,
+        }));
+        self
+    }
+
+    pub(crate) fn pause(mut self, duration_ms: u64) -> Self {
+        self.actions.push(json!({
+            "type": "pause",
+            "duration": duration_ms,
+        }));
+        self
+    }
+
+    /// Press and release, holding the button down in between when a hold is given
+    pub(crate) fn click(self, button: PointerButton, hold_ms: Option<u64>) -> Self {
+        match hold_ms {
+            Some(hold_ms) => self.press(button).pause(hold_ms).release(button),
+            None => self.press(button).release(button),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn actions(&self) -> &[Value] {
+        &self.actions
+    }
+
+    /// Pen pressure ramping from the ends of the stroke up to its middle
+    fn pen_pressure(&self, action: &mut Value, progress: f64) {
+        let PointerType::Pen {
+            pressure,
+            tangential_pressure,
+        } = self.pointer_type
+        else {
+            return;
+        };
+
+        if let Some((ends, middle)) = pressure {
+            let ramp = (std::f64::consts::PI * progress).sin();
+            action["pressure"] = json!((ends + (middle - ends) * ramp).clamp(0.00, 1.00));
+        }
+        if tangential_pressure != 0.00 {
+            action["tangentialPressure"] = json!(tangential_pressure.clamp(-1.00, 1.00));
+        }
+    }
+
+    pub(crate) async fn perform(&self, driver: &WebDriver) -> WebDriverResult<()> {
+        // The type of an input source can't change within a session, touch
+        // and pen get their own
+        let (id, pointer_type) = match self.pointer_type {
+            PointerType::Mouse => (POINTER_ID, "mouse"),
+            PointerType::Touch => ("touch", "touch"),
+            PointerType::Pen { .. } => ("pen", "pen"),
+        };
+        let actions = json!({
+            "actions": [{
+                "type": "pointer",
+                "id": id,
+                "parameters": { "pointerType": pointer_type },
+                "actions": self.actions,
+            }]
+        });
+
+        driver.extension_command(PerformActions(actions)).await?;
+        Ok(())
+    }
+}
+
+/// Scrolls the wheel by the given deltas with the wheel over the middle of the
+/// element, negative deltas scroll up and left
+pub(crate) async fn scroll(
+    driver: &WebDriver,
+    element: &WebElement,
+    delta_x: i64,
+    delta_y: i64,
+) -> WebDriverResult<()> {
+    let actions = json!({
+        "actions": [{
+            "type": "wheel",
+            "id": "wheel",
+            "actions": [{
+                "type": "scroll",
+                "duration": 0,
+                "origin": element.to_json()?,
+                "x": 0,
+                "y": 0,
+                "deltaX": delta_x,
+                "deltaY": delta_y,
+            }],
+        }]
+    });
+
+    driver.extension_command(PerformActions(actions)).await?;
+    Ok(())
+}
+
+/// `POST /session/{id}/actions` with a hand built payload
+#[derive(Debug)]
+struct PerformActions(Value);
+
+impl ExtensionCommand for PerformActions {
+    fn parameters_json(&self) -> Option<Value> {
+        Some(self.0.clone())
+    }
+
+    fn method(&self) -> RequestMethod {
+        RequestMethod::Post
+    }
+
+    fn endpoint(&self) -> Arc<str> {
+        Arc::from("/actions")
+    }
+}
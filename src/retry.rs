@@ -0,0 +1,104 @@
+//! Retrying of WebDriver commands failing with transient errors, such as a
+//! dropped connection or a timeout on a busy Selenium grid.
+
+use std::future::Future;
+
+use thirtyfour::error::WebDriverError;
+
+use crate::error::{MouseActionError, MouseActionResult};
+use crate::time::{sleep, Duration};
+
+/// The longest wait between two attempts
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// How many times and how patiently transient WebDriver errors are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Up to `max_attempts` attempts in total, waiting `backoff_ms` after the
+    /// first failure and twice as long after every following one, but never
+    /// longer than 30 seconds
+    pub fn new(max_attempts: u32, backoff_ms: u64) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            backoff_ms,
+        }
+    }
+
+    /// The waits after each failure in turn, doubling up to the cap
+    fn backoffs(&self) -> impl Iterator<Item = Duration> {
+        let first = Duration::from_millis(self.backoff_ms).min(MAX_BACKOFF);
+        std::iter::successors(Some(first), |backoff| {
+            Some(backoff.saturating_mul(2).min(MAX_BACKOFF))
+        })
+    }
+}
+
+/// Whether the error is likely to go away when the command is sent again
+fn is_transient(err: &MouseActionError) -> bool {
+    matches!(
+        err,
+        MouseActionError::WebDriver(
+            WebDriverError::Timeout(_)
+                | WebDriverError::RequestFailed(_)
+                | WebDriverError::CommandRecvError(_)
+        )
+    )
+}
+
+/// Runs the command, running it again after a transient error as long as the
+/// policy allows, without a policy it runs once
+pub(crate) async fn retry<T, F, Fut>(
+    policy: Option<&RetryPolicy>,
+    mut command: F,
+) -> MouseActionResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = MouseActionResult<T>>,
+{
+    let Some(policy) = policy else {
+        return command().await;
+    };
+
+    let retries = policy.max_attempts as usize - 1;
+    for backoff in policy.backoffs().take(retries) {
+        match command().await {
+            Err(err) if is_transient(&err) => sleep(backoff).await,
+            result => return result,
+        }
+    }
+    command().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let backoffs: Vec<_> = RetryPolicy::new(3, 10_000).backoffs().take(4).collect();
+        assert_eq!(
+            backoffs,
+            [
+                Duration::from_secs(10),
+                Duration::from_secs(20),
+                MAX_BACKOFF,
+                MAX_BACKOFF
+            ]
+        );
+    }
+
+    #[test]
+    fn huge_backoff_saturates_at_the_cap() {
+        let policy = RetryPolicy::new(u32::MAX, u64::MAX / 2);
+        assert!(policy
+            .backoffs()
+            .take(100)
+            .all(|backoff| backoff == MAX_BACKOFF));
+    }
+}
@@ -0,0 +1,29 @@
+//! Timers of the async runtime picked with the `runtime-tokio` (default) or
+//! `runtime-async-std` feature, so the rest of the crate never names a runtime.
+//!
+//! Tokio wins when both features are enabled. Without either there is no timer
+//! and the crate never waits itself: reaction delays, dwells, pacing and retry
+//! backoff are skipped, durations of the actions sent to the browser still apply.
+
+pub(crate) use std::time::{Duration, Instant};
+
+/// Waits for `duration` on the tokio timer
+#[cfg(feature = "runtime-tokio")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for `duration` on the async-std timer
+#[cfg(all(feature = "runtime-async-std", not(feature = "runtime-tokio")))]
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+/// Returns at once, there being no runtime to wait on
+#[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
+pub(crate) async fn sleep(_duration: Duration) {}
+
+/// Waits until `deadline`, returning at once when it already passed
+pub(crate) async fn sleep_until(deadline: Instant) {
+    sleep(deadline.saturating_duration_since(Instant::now())).await;
+}
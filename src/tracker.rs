@@ -0,0 +1,395 @@
+//! Tracking of the mouse position, WebDriver has no command to read it so a
+//! `mousemove` listener records it in the page.
+//!
+//! The last known position of every session is cached after each movement, so
+//! following movements start from it without asking the browser.
+//!
+//! Sessions can mirror the position into `sessionStorage`, so the listener of
+//! a page loaded on the same origin picks it up again without probing. With
+//! the `cdp` feature on Chromium the listener is installed on every new
+//! document and restores it right away, elsewhere only once a movement reads
+//! the position.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+use serde_json::json;
+use thirtyfour::{WebDriver, WebElement};
+
+use crate::error::{MouseActionError, MouseActionResult};
+
+/// Last known mouse position keyed by session id
+fn positions() -> &'static Mutex<HashMap<String, (i64, i64)>> {
+    static POSITIONS: OnceLock<Mutex<HashMap<String, (i64, i64)>>> = OnceLock::new();
+    POSITIONS.get_or_init(Default::default)
+}
+
+/// Sessions mirroring the mouse position into `sessionStorage`
+fn persistent() -> &'static Mutex<HashSet<String>> {
+    static PERSISTENT: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    PERSISTENT.get_or_init(Default::default)
+}
+
+fn is_persistent(driver: &WebDriver) -> bool {
+    persistent()
+        .lock()
+        .unwrap()
+        .contains(&driver.session_id().to_string())
+}
+
+/// Mirror the position into `sessionStorage` from now on, or stop and drop the
+/// stored position
+pub(crate) async fn set_persistent(driver: &WebDriver, persist: bool) -> MouseActionResult<()> {
+    let session_id = driver.session_id().to_string();
+    if persist {
+        persistent().lock().unwrap().insert(session_id);
+    } else {
+        persistent().lock().unwrap().remove(&session_id);
+    }
+    driver.execute(SET_PERSISTENT, vec![json!(persist)]).await?;
+
+    #[cfg(feature = "cdp")]
+    {
+        // The listener reads its persist flag from its arguments
+        let listener = format!("(function () {{{INSTALL_LISTENER}\n}})(true);");
+        crate::cdp::evaluate_on_new_document(driver, persist.then_some(listener.as_str())).await?;
+    }
+    Ok(())
+}
+
+/// Current mouse position, from the cache when known or read from the page otherwise
+///
+/// When the page doesn't know it either the mouse is moved to `fallback`, or to
+/// the middle of the viewport without one
+///
+/// Without `probe` the position is never looked for with a mouse move, an
+/// unknown one is assumed to be the fallback without moving there
+pub(crate) async fn mouse_position(
+    driver: &WebDriver,
+    fallback: Option<(i64, i64)>,
+    probe: bool,
+) -> MouseActionResult<(i64, i64)> {
+    let cached = positions()
+        .lock()
+        .unwrap()
+        .get(&driver.session_id().to_string())
+        .copied();
+
+    match cached {
+        Some(position) => Ok(position),
+        None => {
+            let position = read_mouse_position(driver, fallback, probe).await?;
+            store(driver, position);
+            Ok(position)
+        }
+    }
+}
+
+/// Remember where the mouse was left
+pub(crate) fn store(driver: &WebDriver, position: (i64, i64)) {
+    positions()
+        .lock()
+        .unwrap()
+        .insert(driver.session_id().to_string(), position);
+}
+
+/// Forget the cached position, the next movement reads it from the page again
+pub(crate) fn clear(driver: &WebDriver) {
+    positions()
+        .lock()
+        .unwrap()
+        .remove(&driver.session_id().to_string());
+}
+
+/// Drop everything remembered about the session, so ended sessions don't
+/// pile up in the caches
+pub(crate) fn forget(driver: &WebDriver) {
+    forget_session_id(&driver.session_id().to_string());
+}
+
+fn forget_session_id(session_id: &str) {
+    positions().lock().unwrap().remove(session_id);
+    persistent().lock().unwrap().remove(session_id);
+}
+
+/// Forget the position both in the cache and in the page, the next movement
+/// finds it again as on a fresh document
+pub(crate) async fn reset(driver: &WebDriver) -> MouseActionResult<()> {
+    clear(driver);
+    driver.execute(RESET_POSITION, Vec::new()).await?;
+    Ok(())
+}
+
+/// Record a position as the current one in the cache and in the page without
+/// moving the mouse
+pub(crate) async fn seed(driver: &WebDriver, position: (i64, i64)) -> MouseActionResult<()> {
+    driver
+        .execute(SEED_POSITION, vec![json!(position.0), json!(position.1)])
+        .await?;
+    store(driver, position);
+    Ok(())
+}
+
+/// Size of the viewport in CSS pixels
+pub(crate) async fn viewport_size(driver: &WebDriver) -> MouseActionResult<(i64, i64)> {
+    Ok(driver
+        .execute(VIEWPORT_SIZE, Vec::new())
+        .await?
+        .convert::<(i64, i64)>()?)
+}
+
+/// Device pixels per CSS pixel of the display showing the page
+pub(crate) async fn device_pixel_ratio(driver: &WebDriver) -> MouseActionResult<f64> {
+    Ok(driver
+        .execute(DEVICE_PIXEL_RATIO, Vec::new())
+        .await?
+        .convert::<f64>()?)
+}
+
+/// How far the page is scrolled in CSS pixels
+pub(crate) async fn scroll_offset(driver: &WebDriver) -> MouseActionResult<(f64, f64)> {
+    Ok(driver
+        .execute(SCROLL_OFFSET, Vec::new())
+        .await?
+        .convert::<(f64, f64)>()?)
+}
+
+/// Position of the content of an iframe in the viewport, past its border
+pub(crate) async fn frame_offset(
+    driver: &WebDriver,
+    frame: &WebElement,
+) -> MouseActionResult<(f64, f64)> {
+    Ok(driver
+        .execute(FRAME_OFFSET, vec![frame.to_json()?])
+        .await?
+        .convert::<(f64, f64)>()?)
+}
+
+/// Description of the element drawn on top at the viewport point when it is
+/// neither the element nor one of its descendants, `None` when the point hits it
+pub(crate) async fn covering_element(
+    driver: &WebDriver,
+    element: &WebElement,
+    point: (i64, i64),
+) -> MouseActionResult<Option<String>> {
+    Ok(driver
+        .execute(
+            COVERING_ELEMENT,
+            vec![element.to_json()?, json!(point.0), json!(point.1)],
+        )
+        .await?
+        .convert::<Option<String>>()?)
+}
+
+/// Registers the `mousemove` listener at most once per document, the flag lives
+/// on `window` so a navigation resets it along with the listener
+///
+/// Persisting restores the position stored by a previous page, storage being
+/// unavailable on some documents such as `about:blank` is ignored
+const INSTALL_LISTENER: &str = r#"
+    window.tf_m_persist = arguments[0];
+    if (window.tf_m_persist && (window.tf_m_mouse_x ?? -1) <= -1) {
+        try {
+            const stored = JSON.parse(sessionStorage.getItem("tf_m_mouse") ?? "[-1, -1]");
+            [window.tf_m_mouse_x, window.tf_m_mouse_y] = stored;
+        } catch {}
+    }
+    window.tf_m_mouse_x = window.tf_m_mouse_x ?? -1;
+    window.tf_m_mouse_y = window.tf_m_mouse_y ?? -1;
+
+    if (!window.tf_m_installed) {
+        window.tf_m_installed = true;
+        document.addEventListener("mousemove", (event) => {
+            window.tf_m_mouse_x = event.clientX;
+            window.tf_m_mouse_y = event.clientY;
+            if (window.tf_m_persist) {
+                try {
+                    sessionStorage.setItem("tf_m_mouse", JSON.stringify([event.clientX, event.clientY]));
+                } catch {}
+            }
+        });
+    }"#;
+
+const SET_PERSISTENT: &str = r#"
+    window.tf_m_persist = arguments[0];
+    try {
+        if (arguments[0]) {
+            sessionStorage.setItem("tf_m_mouse", JSON.stringify([window.tf_m_mouse_x ?? -1, window.tf_m_mouse_y ?? -1]));
+        } else {
+            sessionStorage.removeItem("tf_m_mouse");
+        }
+    } catch {}"#;
+
+const READ_POSITION: &str = r#"return [window.tf_m_mouse_x ?? -1, window.tf_m_mouse_y ?? -1];"#;
+
+const RESET_POSITION: &str = r#"
+    window.tf_m_mouse_x = -1;
+    window.tf_m_mouse_y = -1;
+    try {
+        sessionStorage.removeItem("tf_m_mouse");
+    } catch {}"#;
+
+const SEED_POSITION: &str = r#"
+    window.tf_m_mouse_x = arguments[0];
+    window.tf_m_mouse_y = arguments[1];
+    if (window.tf_m_persist) {
+        try {
+            sessionStorage.setItem("tf_m_mouse", JSON.stringify([arguments[0], arguments[1]]));
+        } catch {}
+    }"#;
+
+const VIEWPORT_SIZE: &str = r#"return [window.innerWidth, window.innerHeight];"#;
+
+const DEVICE_PIXEL_RATIO: &str = r#"return window.devicePixelRatio;"#;
+
+const SCROLL_OFFSET: &str = r#"return [window.scrollX, window.scrollY];"#;
+
+const FRAME_OFFSET: &str = r#"
+    const frame = arguments[0];
+    const rect = frame.getBoundingClientRect();
+    return [rect.left + frame.clientLeft, rect.top + frame.clientTop];"#;
+
+/// Hit tests within the shadow root of the element when it is in one, the
+/// document would only report its host
+const COVERING_ELEMENT: &str = r##"
+    const [target, x, y] = arguments;
+    const root = target.getRootNode();
+    const hit = (root.elementFromPoint ? root : document).elementFromPoint(x, y);
+    if (hit !== null && (hit === target || target.contains(hit))) {
+        return null;
+    }
+    if (hit === null) {
+        return "nothing";
+    }
+    const id = hit.id ? "#" + hit.id : "";
+    const classes = [...hit.classList].map((name) => "." + name).join("");
+    return hit.tagName.toLowerCase() + id + classes;"##;
+
+/// Reads the mouse position tracked by the injected `mousemove` listener,
+/// installing the listener first if the position is not yet known
+async fn read_mouse_position(
+    driver: &WebDriver,
+    fallback: Option<(i64, i64)>,
+    probe: bool,
+) -> MouseActionResult<(i64, i64)> {
+    let mut position = tracked_position(driver).await?;
+
+    if is_unknown(position) {
+        let persist = is_persistent(driver);
+        driver
+            .execute(INSTALL_LISTENER, vec![json!(persist)])
+            .await?;
+
+        // The listener restores a persisted position, no probe needed then
+        if persist {
+            position = tracked_position(driver).await?;
+        }
+        if probe && is_unknown(position) {
+            driver.action_chain().move_by_offset(1, 1).perform().await?;
+            position = tracked_position(driver).await?;
+        }
+
+        if is_unknown(position) {
+            return rehome(driver, fallback, probe).await;
+        }
+    }
+
+    if is_outside(position, viewport_size(driver).await?) {
+        return rehome(driver, fallback, probe).await;
+    }
+
+    Ok(position)
+}
+
+/// Whether the listener has not seen the mouse yet, as on a fresh document
+fn is_unknown((x, y): (i64, i64)) -> bool {
+    x <= -1 || y <= -1
+}
+
+/// Whether a tracked position lies outside of the viewport, left over from
+/// another layout
+fn is_outside((x, y): (i64, i64), (width, height): (i64, i64)) -> bool {
+    x >= width || y >= height
+}
+
+/// Middle of the viewport, `None` for a viewport without area
+fn viewport_center((width, height): (i64, i64)) -> Option<(i64, i64)> {
+    let (center_x, center_y) = (width / 2, height / 2);
+    (center_x > 0 && center_y > 0).then_some((center_x, center_y))
+}
+
+/// Position recorded by the listener, `-1` when unknown
+async fn tracked_position(driver: &WebDriver) -> MouseActionResult<(i64, i64)> {
+    Ok(driver
+        .execute(READ_POSITION, Vec::new())
+        .await?
+        .convert::<(i64, i64)>()?)
+}
+
+/// Moves the mouse to the fallback position or the middle of the viewport,
+/// used when the probe move did not reach the listener, such as on a fresh
+/// document after a navigation, so the position is known again without erroring
+///
+/// Only picks the position without `move_mouse`, the next movement jumps there
+/// with its first move
+async fn rehome(
+    driver: &WebDriver,
+    fallback: Option<(i64, i64)>,
+    move_mouse: bool,
+) -> MouseActionResult<(i64, i64)> {
+    let (home_x, home_y) = match fallback {
+        Some((x, y)) => (x.max(0), y.max(0)),
+        None => viewport_center(viewport_size(driver).await?)
+            .ok_or(MouseActionError::PositionUnavailable)?,
+    };
+
+    if move_mouse {
+        driver
+            .action_chain()
+            .move_to(home_x, home_y)
+            .perform()
+            .await?;
+    }
+
+    Ok((home_x, home_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forgotten_session_leaves_the_caches() {
+        let session_id = "forgotten-session";
+        positions()
+            .lock()
+            .unwrap()
+            .insert(session_id.to_string(), (10, 20));
+        persistent().lock().unwrap().insert(session_id.to_string());
+
+        forget_session_id(session_id);
+        assert!(!positions().lock().unwrap().contains_key(session_id));
+        assert!(!persistent().lock().unwrap().contains(session_id));
+    }
+
+    #[test]
+    fn fresh_document_position_is_unknown() {
+        assert!(is_unknown((-1, -1)));
+        assert!(is_unknown((200, -1)));
+        assert!(!is_unknown((0, 0)));
+    }
+
+    #[test]
+    fn position_past_the_viewport_is_outside() {
+        assert!(is_outside((1280, 300), (1280, 720)));
+        assert!(is_outside((300, 900), (1280, 720)));
+        assert!(!is_outside((1279, 719), (1280, 720)));
+    }
+
+    #[test]
+    fn rehomes_to_the_viewport_center() {
+        assert_eq!(viewport_center((1280, 720)), Some((640, 360)));
+        assert_eq!(viewport_center((1, 720)), None);
+        assert_eq!(viewport_center((0, 0)), None);
+    }
+}